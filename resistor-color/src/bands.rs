@@ -0,0 +1,161 @@
+use std::fmt;
+
+use enum_iterator::all;
+use int_enum::IntEnum;
+
+use crate::resistance::scaled;
+use crate::{ESeries, Resistance, ResistorColor};
+
+/// Why a band sequence could not be decoded.
+///
+/// Band positions count from 0, left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Resistors have 3 to 6 bands.
+    BandCount(usize),
+    /// `color` has no meaning at `position`, e.g. Gold as a digit.
+    InvalidBand {
+        position: usize,
+        color: ResistorColor,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::BandCount(count) => {
+                write!(f, "expected 3 to 6 bands, got {}", count)
+            }
+            DecodeError::InvalidBand { position, color } => {
+                write!(f, "{} is not valid at band {}", color, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Tolerance of a 3-band resistor, which has no tolerance band.
+const UNMARKED_TOLERANCE: f64 = 20.0;
+
+fn band<T>(
+    bands: &[ResistorColor],
+    position: usize,
+    meaning: impl Fn(ResistorColor) -> Option<T>,
+) -> Result<T, DecodeError> {
+    let color = bands[position];
+    meaning(color).ok_or(DecodeError::InvalidBand { position, color })
+}
+
+/// Decodes a band sequence, read left to right:
+///
+/// - 3 bands: two digits and a multiplier, ±20%,
+/// - 4 bands: two digits, a multiplier and a tolerance,
+/// - 5 bands: three digits, a multiplier and a tolerance,
+/// - 6 bands: like 5, followed by a temperature coefficient.
+pub fn decode(bands: &[ResistorColor]) -> Result<Resistance, DecodeError> {
+    let digits = match bands.len() {
+        3 | 4 => 2,
+        5 | 6 => 3,
+        count => return Err(DecodeError::BandCount(count)),
+    };
+    let mut significand = 0;
+    for position in 0..digits {
+        significand = significand * 10 + band(bands, position, ResistorColor::digit)?;
+    }
+    let exponent = bands[digits].multiplier_exponent();
+    let tolerance = match bands.len() {
+        3 => UNMARKED_TOLERANCE,
+        _ => band(bands, digits + 1, ResistorColor::tolerance)?,
+    };
+    let temperature_coefficient = match bands.len() {
+        6 => Some(band(bands, 5, ResistorColor::temperature_coefficient)?),
+        _ => None,
+    };
+    Ok(Resistance {
+        ohms: scaled(significand, exponent),
+        tolerance,
+        temperature_coefficient,
+    })
+}
+
+/// Why a resistance could not be encoded as bands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncodeError {
+    /// Only 4- and 5-band encodings are supported.
+    BandCount(usize),
+    /// The value isn't positive, or needs a multiplier no colour stands for.
+    OutOfRange(f64),
+    /// No colour stands for this tolerance.
+    Tolerance(f64),
+    /// The standard value has three significant digits, which 4 bands can't hold.
+    Digits(f64),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::BandCount(count) => {
+                write!(f, "expected 4 or 5 bands, got {}", count)
+            }
+            EncodeError::OutOfRange(ohms) => write!(f, "{}Ω can't be encoded", ohms),
+            EncodeError::Tolerance(tolerance) => {
+                write!(f, "no band color for ±{}%", tolerance)
+            }
+            EncodeError::Digits(ohms) => {
+                write!(f, "{}Ω needs three digits, use 5 bands", ohms)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Bands for a standard value near a target resistance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Encoding {
+    pub bands: Vec<ResistorColor>,
+    /// What the bands decode to.
+    pub resistance: Resistance,
+    /// How far the standard value is from the target, in percent of the target.
+    pub error: f64,
+}
+
+/// Snaps `target` to the nearest value of `series` and encodes it as 4 or 5
+/// bands. The temperature coefficient is ignored.
+pub fn encode(target: Resistance, series: ESeries, bands: usize) -> Result<Encoding, EncodeError> {
+    let digits = match bands {
+        4 => 2,
+        5 => 3,
+        count => return Err(EncodeError::BandCount(count)),
+    };
+    if !(target.ohms > 0.0 && target.ohms.is_finite()) {
+        return Err(EncodeError::OutOfRange(target.ohms));
+    }
+    let tolerance = all::<ResistorColor>()
+        .find(|color| color.tolerance() == Some(target.tolerance))
+        .ok_or(EncodeError::Tolerance(target.tolerance))?;
+    let (mut significand, mut exponent) = series.nearest_parts(target.ohms);
+    if digits == 2 {
+        if significand % 10 != 0 {
+            return Err(EncodeError::Digits(scaled(significand, exponent)));
+        }
+        significand /= 10;
+        exponent += 1;
+    }
+    let multiplier = all::<ResistorColor>()
+        .find(|color| color.multiplier_exponent() == exponent)
+        .ok_or(EncodeError::OutOfRange(target.ohms))?;
+    let mut bands: Vec<ResistorColor> = significand
+        .to_string()
+        .chars()
+        .map(|digit| ResistorColor::from_int(digit.to_digit(10).unwrap()).unwrap())
+        .collect();
+    bands.extend([multiplier, tolerance]);
+    let resistance = decode(&bands).expect("encoded bands are valid");
+    Ok(Encoding {
+        error: (resistance.ohms - target.ohms) / target.ohms * 100.0,
+        bands,
+        resistance,
+    })
+}
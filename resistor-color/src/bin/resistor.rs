@@ -0,0 +1,163 @@
+use std::env;
+use std::process;
+
+use enum_iterator::all;
+use serde_json::{json, Value};
+
+use resistor_color::{
+    decode, encode, parse_bands, parse_ohms, parse_smd, ESeries, Resistance, ResistorColor,
+};
+
+const USAGE: &str = "\
+usage: resistor [--json] decode <color>... | <smd code>
+       resistor [--json] encode <ohms> [--bands 4|5] [--series E6|E12|E24|E48|E96|E192] [--tolerance <percent>]
+       resistor [--json] list";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn resistance_json(resistance: &Resistance) -> Value {
+    json!({
+        "ohms": resistance.ohms,
+        "tolerance": resistance.tolerance,
+        "temperature_coefficient": resistance.temperature_coefficient,
+        "text": resistance.to_string(),
+    })
+}
+
+fn run_decode(args: &[String], as_json: bool) -> Result<String, String> {
+    let resistance = match parse_bands(&args.join(" ")) {
+        Ok(bands) => decode(&bands).map_err(|err| err.to_string())?,
+        Err(err) => match args {
+            [code] => parse_smd(code).map_err(|_| err.to_string())?,
+            _ => return Err(err.to_string()),
+        },
+    };
+    Ok(match as_json {
+        true => resistance_json(&resistance).to_string(),
+        false => resistance.to_string(),
+    })
+}
+
+fn parse_series(text: &str) -> Option<ESeries> {
+    all::<ESeries>().find(|series| format!("{:?}", series).eq_ignore_ascii_case(text))
+}
+
+fn run_encode(args: &[String], as_json: bool) -> Result<String, String> {
+    let (value, options) = args.split_first().unwrap_or_else(|| usage());
+    let ohms = parse_ohms(value).ok_or_else(|| format!("invalid value `{}`", value))?;
+    let mut bands = 4;
+    let mut series = ESeries::E24;
+    let mut tolerance = None;
+    for pair in options.chunks(2) {
+        match pair {
+            [flag, value] if flag == "--bands" => {
+                bands = value
+                    .parse()
+                    .map_err(|_| format!("invalid band count `{}`", value))?
+            }
+            [flag, value] if flag == "--series" => {
+                series = parse_series(value).ok_or_else(|| format!("unknown series `{}`", value))?
+            }
+            [flag, value] if flag == "--tolerance" => {
+                let percent = value.trim_end_matches('%');
+                tolerance = Some(
+                    percent
+                        .parse()
+                        .map_err(|_| format!("invalid tolerance `{}`", value))?,
+                )
+            }
+            _ => usage(),
+        }
+    }
+    let target = Resistance {
+        ohms,
+        tolerance: tolerance.unwrap_or(if bands == 4 { 5.0 } else { 1.0 }),
+        temperature_coefficient: None,
+    };
+    let encoding = encode(target, series, bands).map_err(|err| err.to_string())?;
+    let colors: Vec<String> = encoding.bands.iter().map(|c| c.to_string()).collect();
+    if as_json {
+        let mut value = resistance_json(&encoding.resistance);
+        value["bands"] = json!(colors);
+        value["error"] = json!(encoding.error);
+        return Ok(value.to_string());
+    }
+    let mut output = colors.join(" ");
+    if encoding.error != 0.0 {
+        output += &format!(
+            "\nsnapped to {} ({:+.2}%)",
+            encoding.resistance, encoding.error
+        );
+    }
+    Ok(output)
+}
+
+fn run_list(as_json: bool) -> String {
+    let colors = all::<ResistorColor>();
+    if as_json {
+        let colors: Vec<Value> = colors
+            .map(|color| {
+                json!({
+                    "name": color.to_string(),
+                    "iec_code": color.iec_code(),
+                    "short_code": color.code().to_string(),
+                    "digit": color.digit(),
+                    "multiplier_exponent": color.multiplier_exponent(),
+                    "tolerance": color.tolerance(),
+                    "temperature_coefficient": color.temperature_coefficient(),
+                })
+            })
+            .collect();
+        return Value::from(colors).to_string();
+    }
+    let dash = || "-".to_string();
+    let mut lines = vec![format!(
+        "{:<7} {:<3} {:<5} {:<5} {:<10} {:<9} {}",
+        "color", "iec", "short", "digit", "multiplier", "tolerance", "tempco"
+    )];
+    for color in colors {
+        lines.push(format!(
+            "{:<7} {:<3} {:<5} {:<5} {:<10} {:<9} {}",
+            color.to_string(),
+            color.iec_code(),
+            color.code(),
+            color.digit().map_or_else(dash, |digit| digit.to_string()),
+            format!("10^{}", color.multiplier_exponent()),
+            color
+                .tolerance()
+                .map_or_else(dash, |percent| format!("±{}%", percent)),
+            color
+                .temperature_coefficient()
+                .map_or_else(dash, |ppm| format!("{}ppm/K", ppm)),
+        ));
+    }
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let as_json = args.iter().any(|arg| arg == "--json");
+    args.retain(|arg| arg != "--json");
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "decode" && !rest.is_empty() => {
+            run_decode(rest, as_json)
+        }
+        Some((command, rest)) if command == "encode" => run_encode(rest, as_json),
+        Some((command, [])) if command == "list" => Ok(run_list(as_json)),
+        _ => usage(),
+    };
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
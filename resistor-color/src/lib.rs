@@ -1,6 +1,24 @@
 use enum_iterator::{all, Sequence};
 use int_enum::IntEnum;
 
+pub mod bands;
+pub mod network;
+pub mod parse;
+pub mod resistance;
+pub mod series;
+pub mod smd;
+pub mod svg;
+
+pub use bands::{decode, encode, DecodeError, EncodeError, Encoding};
+pub use network::{solve, Network, Solution};
+pub use parse::{parse_bands, ParseColorError};
+pub use resistance::{parse_ohms, Resistance};
+pub use series::ESeries;
+pub use smd::{parse_smd, smd_code, SmdError, SmdFormat};
+pub use svg::{render_svg, Palette};
+
+/// A band colour. Black to White are the digit colours; Gold, Silver and
+/// Pink only appear as multiplier, tolerance or temperature bands.
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntEnum, Sequence)]
 pub enum ResistorColor {
@@ -14,6 +32,9 @@ pub enum ResistorColor {
     Violet = 7,
     Grey = 8,
     White = 9,
+    Gold = 10,
+    Silver = 11,
+    Pink = 12,
 }
 
 impl std::fmt::Display for ResistorColor {
@@ -29,21 +50,81 @@ impl std::fmt::Display for ResistorColor {
             ResistorColor::Violet => write!(f, "Violet"),
             ResistorColor::Grey => write!(f, "Grey"),
             ResistorColor::White => write!(f, "White"),
+            ResistorColor::Gold => write!(f, "Gold"),
+            ResistorColor::Silver => write!(f, "Silver"),
+            ResistorColor::Pink => write!(f, "Pink"),
+        }
+    }
+}
+
+impl ResistorColor {
+    /// The digit a significant-figure band stands for.
+    pub fn digit(self) -> Option<u32> {
+        match self.int_value() {
+            digit @ 0..=9 => Some(digit),
+            _ => None,
+        }
+    }
+
+    /// The power of ten a multiplier band stands for.
+    pub fn multiplier_exponent(self) -> i32 {
+        match self {
+            ResistorColor::Gold => -1,
+            ResistorColor::Silver => -2,
+            ResistorColor::Pink => -3,
+            color => color.int_value() as i32,
+        }
+    }
+
+    /// The tolerance in percent, as given by IEC 60062.
+    pub fn tolerance(self) -> Option<f64> {
+        match self {
+            ResistorColor::Brown => Some(1.0),
+            ResistorColor::Red => Some(2.0),
+            ResistorColor::Orange => Some(0.05),
+            ResistorColor::Yellow => Some(0.02),
+            ResistorColor::Green => Some(0.5),
+            ResistorColor::Blue => Some(0.25),
+            ResistorColor::Violet => Some(0.1),
+            ResistorColor::Grey => Some(0.01),
+            ResistorColor::Gold => Some(5.0),
+            ResistorColor::Silver => Some(10.0),
+            _ => None,
+        }
+    }
+
+    /// The temperature coefficient in ppm/K, as given by IEC 60062.
+    pub fn temperature_coefficient(self) -> Option<u32> {
+        match self {
+            ResistorColor::Black => Some(250),
+            ResistorColor::Brown => Some(100),
+            ResistorColor::Red => Some(50),
+            ResistorColor::Orange => Some(15),
+            ResistorColor::Yellow => Some(25),
+            ResistorColor::Green => Some(20),
+            ResistorColor::Blue => Some(10),
+            ResistorColor::Violet => Some(5),
+            ResistorColor::Grey => Some(1),
+            _ => None,
         }
     }
 }
 
+/// Only meaningful for the digit colours, see [`ResistorColor::digit`].
 pub fn color_to_value(_color: ResistorColor) -> u32 {
     _color.int_value()
 }
 
 pub fn value_to_color_string(value: u32) -> String {
     match ResistorColor::from_int(value) {
-        Ok(color) => return color.to_string(),
-        Err(_) => return "value out of range".to_string(),
+        Ok(color) if color.digit().is_some() => color.to_string(),
+        _ => "value out of range".to_string(),
     }
 }
 
+/// The digit colours, Black to White.
 pub fn colors() -> Vec<ResistorColor> {
-    all::<ResistorColor>().collect()
+    all::<ResistorColor>()
+        .filter(|color| color.digit().is_some())
+        .collect()
 }
@@ -0,0 +1,332 @@
+use std::fmt;
+
+use crate::resistance::{format_ohms, scaled};
+use crate::{encode, ESeries, EncodeError, Resistance, ResistorColor};
+
+/// Resistors wired together. Children are kept sorted by value and nested
+/// networks of the same kind are flattened, so equal networks compare equal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Network {
+    Part(f64),
+    Series(Vec<Network>),
+    Parallel(Vec<Network>),
+}
+
+impl Network {
+    pub fn ohms(&self) -> f64 {
+        match self {
+            Network::Part(ohms) => *ohms,
+            Network::Series(parts) => parts.iter().map(Network::ohms).sum(),
+            Network::Parallel(parts) => {
+                1.0 / parts.iter().map(|part| 1.0 / part.ohms()).sum::<f64>()
+            }
+        }
+    }
+
+    /// The value of every part, left to right.
+    pub fn parts(&self) -> Vec<f64> {
+        match self {
+            Network::Part(ohms) => vec![*ohms],
+            Network::Series(parts) | Network::Parallel(parts) => {
+                parts.iter().flat_map(Network::parts).collect()
+            }
+        }
+    }
+
+    pub fn part_count(&self) -> usize {
+        match self {
+            Network::Part(_) => 1,
+            Network::Series(parts) | Network::Parallel(parts) => {
+                parts.iter().map(Network::part_count).sum()
+            }
+        }
+    }
+
+    fn join(self, other: Network, parallel: bool) -> Network {
+        let mut parts = vec![];
+        for network in [self, other] {
+            match (network, parallel) {
+                (Network::Series(inner), false) | (Network::Parallel(inner), true) => {
+                    parts.extend(inner)
+                }
+                (network, _) => parts.push(network),
+            }
+        }
+        parts.sort_by(|a, b| a.ohms().total_cmp(&b.ohms()));
+        match parallel {
+            true => Network::Parallel(parts),
+            false => Network::Series(parts),
+        }
+    }
+}
+
+/// `+` joins parts in series and `∥` in parallel, e.g. `1kΩ + (2.2kΩ ∥ 3.3kΩ)`.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (parts, separator) = match self {
+            Network::Part(ohms) => return write!(f, "{}", format_ohms(*ohms)),
+            Network::Series(parts) => (parts, " + "),
+            Network::Parallel(parts) => (parts, " ∥ "),
+        };
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", separator)?;
+            }
+            match part {
+                Network::Part(_) => write!(f, "{}", part)?,
+                _ => write!(f, "({})", part)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A network of standard parts close to a target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub network: Network,
+    /// How far the network is from the target, in percent of the target.
+    pub error: f64,
+    /// The bands of each part, in the order of [`Network::parts`].
+    pub bands: Vec<Vec<ResistorColor>>,
+}
+
+/// How many solutions [`solve`] returns at most.
+pub const SOLUTIONS: usize = 10;
+
+/// How many of the most promising networks [`solve`] searches further at
+/// the top level; each level below searches half as many, but at least one.
+const BEAM: usize = 8;
+
+/// Standard values are taken from this many decades either side of the target.
+const DECADES: i32 = 2;
+
+/// A network of one or two parts, by index into [`Solver::values`]. There are
+/// about as many as the square of the number of values, so they're kept small.
+#[derive(Debug, Clone, Copy)]
+enum Small {
+    Part(usize),
+    Pair { a: usize, b: usize, parallel: bool },
+}
+
+impl Small {
+    fn part_count(self) -> usize {
+        match self {
+            Small::Part(_) => 1,
+            Small::Pair { .. } => 2,
+        }
+    }
+}
+
+struct Solver {
+    /// Sorted, and limited to values that can be marked with bands.
+    values: Vec<f64>,
+    /// Every single part, by value.
+    singles: Vec<(f64, Small)>,
+    /// Every network of one or two parts by value, with one network per value,
+    /// the one with fewer parts where there's a choice.
+    smalls: Vec<(f64, Small)>,
+}
+
+impl Solver {
+    fn new(values: Vec<f64>) -> Self {
+        let singles: Vec<(f64, Small)> = values
+            .iter()
+            .enumerate()
+            .map(|(i, &ohms)| (ohms, Small::Part(i)))
+            .collect();
+        let mut smalls = singles.clone();
+        for a in 0..values.len() {
+            for b in a..values.len() {
+                let (x, y) = (values[a], values[b]);
+                smalls.push((
+                    x + y,
+                    Small::Pair {
+                        a,
+                        b,
+                        parallel: false,
+                    },
+                ));
+                smalls.push((
+                    1.0 / (1.0 / x + 1.0 / y),
+                    Small::Pair {
+                        a,
+                        b,
+                        parallel: true,
+                    },
+                ));
+            }
+        }
+        smalls.sort_by(|(x, p), (y, q)| x.total_cmp(y).then(p.part_count().cmp(&q.part_count())));
+        smalls.dedup_by(|(later, _), (earlier, _)| later == earlier);
+        Solver {
+            values,
+            singles,
+            smalls,
+        }
+    }
+
+    fn network(&self, small: Small) -> Network {
+        match small {
+            Small::Part(i) => Network::Part(self.values[i]),
+            Small::Pair { a, b, parallel } => {
+                Network::Part(self.values[a]).join(Network::Part(self.values[b]), parallel)
+            }
+        }
+    }
+
+    /// The networks of up to `max_parts`, at most 2, closest to `ohms` from
+    /// below and above. One of them is the best there is.
+    fn nearest(&self, ohms: f64, max_parts: usize) -> Vec<Network> {
+        let table = match max_parts {
+            1 => &self.singles,
+            _ => &self.smalls,
+        };
+        let i = table.partition_point(|&(value, _)| value < ohms);
+        [i.checked_sub(1), Some(i)]
+            .into_iter()
+            .filter_map(|i| table.get(i?))
+            .map(|&(_, small)| self.network(small))
+            .collect()
+    }
+
+    /// Networks of up to `max_parts` close to `ohms`.
+    ///
+    /// Each is found exactly up to 3 parts: the nearest network of up to 2,
+    /// or a part in series or parallel with one, looked up by value. Beyond
+    /// that, a part or pair `x` is joined with a rest of up to 2 parts as an
+    /// estimate, and only the `beam` best estimates get their rest searched
+    /// with all the parts left. Pairs are only tried as `x` where they would
+    /// be the larger half of a series or the smaller of a parallel network,
+    /// which is where `(a ∥ b) + (c ∥ d)` and friends come from.
+    fn candidates(&self, ohms: f64, max_parts: usize, beam: usize) -> Vec<Network> {
+        let mut networks = self.nearest(ohms, max_parts.min(2));
+        if max_parts < 3 {
+            return networks;
+        }
+        let pairs = match max_parts {
+            3 => &[][..],
+            _ => {
+                let from = self
+                    .smalls
+                    .partition_point(|&(value, _)| value < ohms / 2.0);
+                let to = self
+                    .smalls
+                    .partition_point(|&(value, _)| value <= ohms * 2.0);
+                &self.smalls[from..to]
+            }
+        };
+        let xs = self
+            .singles
+            .iter()
+            .chain(pairs.iter().filter(|(_, small)| small.part_count() == 2));
+        let mut estimates = vec![];
+        for &(value, x) in xs {
+            let (parallel, rest) = match value < ohms {
+                true => (false, ohms - value),
+                false if value > ohms => (true, 1.0 / (1.0 / ohms - 1.0 / value)),
+                false => continue,
+            };
+            let rest_parts = max_parts - x.part_count();
+            let x = self.network(x);
+            let joined = self
+                .nearest(rest, rest_parts.min(2))
+                .into_iter()
+                .map(|y| x.clone().join(y, parallel));
+            match rest_parts > 2 {
+                true => {
+                    let estimate = joined.min_by_key(|network| rank(network, ohms)).unwrap();
+                    estimates.push((estimate, x, parallel, rest, rest_parts));
+                }
+                false => networks.extend(joined),
+            }
+        }
+        estimates.sort_by_cached_key(|(estimate, ..)| rank(estimate, ohms));
+        for (i, (estimate, x, parallel, rest, rest_parts)) in estimates.into_iter().enumerate() {
+            if i < beam {
+                let y = self.best(rest, rest_parts, (beam / 2).max(1));
+                networks.push(x.join(y, parallel));
+            }
+            networks.push(estimate);
+        }
+        networks
+    }
+
+    fn best(&self, ohms: f64, max_parts: usize, beam: usize) -> Network {
+        self.candidates(ohms, max_parts, beam)
+            .into_iter()
+            .min_by_key(|network| rank(network, ohms))
+            .unwrap()
+    }
+}
+
+/// Orders networks by error, then part count. Errors are compared in ppm so
+/// that rounding noise doesn't hide a tie.
+fn rank(network: &Network, ohms: f64) -> (u64, usize) {
+    let ppm = ((network.ohms() - ohms).abs() / ohms * 1e6).round() as u64;
+    (ppm, network.part_count())
+}
+
+/// Finds the best networks of up to `max_parts` standard values of `series`
+/// for `target`, ranked by error and then part count.
+///
+/// Every part gets `target`'s tolerance and is marked with 4 bands up to
+/// E24, 5 above. Up to 3 parts the best network is always found; beyond
+/// that the search follows only the most promising few networks at each
+/// level, so it takes time in proportion to `max_parts` but may miss the
+/// very best network.
+pub fn solve(
+    target: Resistance,
+    series: ESeries,
+    max_parts: usize,
+) -> Result<Vec<Solution>, EncodeError> {
+    let bands = match series {
+        ESeries::E6 | ESeries::E12 | ESeries::E24 => 4,
+        _ => 5,
+    };
+    let part = |ohms: f64| Resistance {
+        ohms,
+        tolerance: target.tolerance,
+        temperature_coefficient: None,
+    };
+    encode(target, series, bands)?;
+    let decade = target.ohms.log10().floor() as i32;
+    let mut values: Vec<f64> = (decade - DECADES - 2..=decade + DECADES - 2)
+        .flat_map(|exponent| {
+            series
+                .significands()
+                .into_iter()
+                .map(move |significand| scaled(significand, exponent))
+        })
+        .filter(|&ohms| encode(part(ohms), series, bands).is_ok())
+        .collect();
+    values.sort_by(f64::total_cmp);
+    let solver = Solver::new(values);
+
+    let mut networks = match max_parts {
+        0 => vec![],
+        _ => solver.candidates(target.ohms, max_parts, BEAM),
+    };
+    networks.sort_by_key(|network| rank(network, target.ohms));
+    let mut unique: Vec<Network> = vec![];
+    for network in networks {
+        if unique.len() == SOLUTIONS {
+            break;
+        }
+        if !unique.contains(&network) {
+            unique.push(network);
+        }
+    }
+    Ok(unique
+        .into_iter()
+        .map(|network| Solution {
+            error: (network.ohms() - target.ohms) / target.ohms * 100.0,
+            bands: network
+                .parts()
+                .into_iter()
+                .map(|ohms| encode(part(ohms), series, bands).unwrap().bands)
+                .collect(),
+            network,
+        })
+        .collect())
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::str::FromStr;
+
+use enum_iterator::all;
+
+use crate::ResistorColor;
+
+/// A token that doesn't name a band color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    pub token: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown color `{}`", self.token)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl ResistorColor {
+    /// The two-letter code from IEC 60757, e.g. `BN` for Brown.
+    pub fn iec_code(self) -> &'static str {
+        match self {
+            ResistorColor::Black => "BK",
+            ResistorColor::Brown => "BN",
+            ResistorColor::Red => "RD",
+            ResistorColor::Orange => "OG",
+            ResistorColor::Yellow => "YE",
+            ResistorColor::Green => "GN",
+            ResistorColor::Blue => "BU",
+            ResistorColor::Violet => "VT",
+            ResistorColor::Grey => "GY",
+            ResistorColor::White => "WH",
+            ResistorColor::Gold => "GD",
+            ResistorColor::Silver => "SR",
+            ResistorColor::Pink => "PK",
+        }
+    }
+
+    /// A single-letter shorthand that is this crate's own convention, not a
+    /// standard; prefer [`ResistorColor::iec_code`] for anything shared.
+    pub fn code(self) -> char {
+        match self {
+            ResistorColor::Black => 'K',
+            ResistorColor::Brown => 'N',
+            ResistorColor::Red => 'R',
+            ResistorColor::Orange => 'O',
+            ResistorColor::Yellow => 'Y',
+            ResistorColor::Green => 'G',
+            ResistorColor::Blue => 'U',
+            ResistorColor::Violet => 'V',
+            ResistorColor::Grey => 'A',
+            ResistorColor::White => 'W',
+            ResistorColor::Gold => 'D',
+            ResistorColor::Silver => 'S',
+            ResistorColor::Pink => 'P',
+        }
+    }
+}
+
+/// Reads a color name in any case, one of the aliases "gray" and "purple",
+/// an [`ResistorColor::iec_code`] or a single-letter [`ResistorColor::code`].
+impl FromStr for ResistorColor {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alias = match s.to_ascii_lowercase().as_str() {
+            "gray" => Some(ResistorColor::Grey),
+            "purple" => Some(ResistorColor::Violet),
+            _ => None,
+        };
+        all::<ResistorColor>()
+            .find(|color| {
+                color.to_string().eq_ignore_ascii_case(s)
+                    || color.iec_code().eq_ignore_ascii_case(s)
+                    || s.eq_ignore_ascii_case(color.code().encode_utf8(&mut [0; 4]))
+            })
+            .or(alias)
+            .ok_or_else(|| ParseColorError {
+                token: s.to_string(),
+            })
+    }
+}
+
+/// Reads a band sequence such as `"brown-black-red-gold"`. Colors may be
+/// separated by `-`, `,` or whitespace.
+pub fn parse_bands(text: &str) -> Result<Vec<ResistorColor>, ParseColorError> {
+    text.split(|c: char| c == '-' || c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(str::parse)
+        .collect()
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+/// A resistor's nominal value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resistance {
+    pub ohms: f64,
+    /// In percent, either way.
+    pub tolerance: f64,
+    /// In ppm/K, when the part is marked with one.
+    pub temperature_coefficient: Option<u32>,
+}
+
+/// `significand * 10^exponent`, dividing for negative exponents so that
+/// e.g. 4.7 comes out exact where multiplying by 0.1 wouldn't.
+pub(crate) fn scaled(significand: u32, exponent: i32) -> f64 {
+    match exponent {
+        0.. => significand as f64 * 10f64.powi(exponent),
+        _ => significand as f64 / 10f64.powi(-exponent),
+    }
+}
+
+/// Reads a value such as `"4700"`, `"4.7k"`, `"4k7"`, `"R47"`, `"1MΩ"` or
+/// `"10 ohms"`.
+///
+/// As in schematics, a prefix letter may stand in for the decimal point,
+/// with `R` for plain ohms. Prefixes ignore case, so `m` means mega, never
+/// milli. Whitespace is allowed before the unit.
+pub fn parse_ohms(text: &str) -> Option<f64> {
+    let lower = text.trim().to_ascii_lowercase();
+    let text = ["ohms", "ohm", "Ω"]
+        .iter()
+        .find_map(|unit| lower.strip_suffix(unit))
+        .unwrap_or(&lower)
+        .trim_end();
+    let Some(position) = text.find(|c: char| c.is_ascii_alphabetic()) else {
+        return text
+            .parse()
+            .ok()
+            .filter(|ohms: &f64| *ohms >= 0.0 && ohms.is_finite());
+    };
+    let prefix = match &text[position..position + 1] {
+        "r" => 0,
+        "k" => 3,
+        "m" => 6,
+        "g" => 9,
+        _ => return None,
+    };
+    let (whole, fraction) = (&text[..position], &text[position + 1..]);
+    let (whole, fraction) = match whole.split_once('.') {
+        Some(_) if !fraction.is_empty() => return None,
+        Some((whole, fraction)) => (whole, fraction),
+        None => (whole, fraction),
+    };
+    let digits = format!("{}{}", whole, fraction);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(scaled(digits.parse().ok()?, prefix - fraction.len() as i32))
+}
+
+const PREFIXES: [(f64, &str); 3] = [(1e9, "G"), (1e6, "M"), (1e3, "k")];
+
+/// Formats `value` with at most three decimals and no trailing zeros.
+fn trimmed(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Formats `ohms` with an SI prefix, e.g. `4.7kΩ`.
+pub(crate) fn format_ohms(ohms: f64) -> String {
+    let (scale, prefix) = PREFIXES
+        .iter()
+        .find(|(scale, _)| ohms >= *scale)
+        .copied()
+        .unwrap_or((1.0, ""));
+    format!("{}{}Ω", trimmed(ohms / scale), prefix)
+}
+
+impl fmt::Display for Resistance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ±{}%",
+            format_ohms(self.ohms),
+            trimmed(self.tolerance)
+        )?;
+        if let Some(ppm) = self.temperature_coefficient {
+            write!(f, " {}ppm/K", ppm)?;
+        }
+        Ok(())
+    }
+}
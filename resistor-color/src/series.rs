@@ -0,0 +1,77 @@
+use enum_iterator::Sequence;
+
+use crate::resistance::scaled;
+
+/// The IEC 60063 preferred number series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum ESeries {
+    E6,
+    E12,
+    E24,
+    E48,
+    E96,
+    E192,
+}
+
+const E24: [u32; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30, 33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+const E192: [u32; 192] = [
+    100, 101, 102, 104, 105, 106, 107, 109, 110, 111, 113, 114, 115, 117, 118, 120, 121, 123, 124,
+    126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 142, 143, 145, 147, 149, 150, 152, 154, 156,
+    158, 160, 162, 164, 165, 167, 169, 172, 174, 176, 178, 180, 182, 184, 187, 189, 191, 193, 196,
+    198, 200, 203, 205, 208, 210, 213, 215, 218, 221, 223, 226, 229, 232, 234, 237, 240, 243, 246,
+    249, 252, 255, 258, 261, 264, 267, 271, 274, 277, 280, 284, 287, 291, 294, 298, 301, 305, 309,
+    312, 316, 320, 324, 328, 332, 336, 340, 344, 348, 352, 357, 361, 365, 370, 374, 379, 383, 388,
+    392, 397, 402, 407, 412, 417, 422, 427, 432, 437, 442, 448, 453, 459, 464, 470, 475, 481, 487,
+    493, 499, 505, 511, 517, 523, 530, 536, 542, 549, 556, 562, 569, 576, 583, 590, 597, 604, 612,
+    619, 626, 634, 642, 649, 657, 665, 673, 681, 690, 698, 706, 715, 723, 732, 741, 750, 759, 768,
+    777, 787, 796, 806, 816, 825, 835, 845, 856, 866, 876, 887, 898, 909, 920, 931, 942, 953, 965,
+    976, 988,
+];
+
+impl ESeries {
+    /// The three significant digits of every value in one decade, from 100 to 988.
+    ///
+    /// Each series up to E24 and from E48 on is every other value of the next one.
+    pub fn significands(self) -> Vec<u32> {
+        let (table, step) = match self {
+            ESeries::E6 => (&E24[..], 4),
+            ESeries::E12 => (&E24[..], 2),
+            ESeries::E24 => (&E24[..], 1),
+            ESeries::E48 => (&E192[..], 4),
+            ESeries::E96 => (&E192[..], 2),
+            ESeries::E192 => (&E192[..], 1),
+        };
+        let scale = 100 / table[0];
+        table
+            .iter()
+            .step_by(step)
+            .map(|&value| value * scale)
+            .collect()
+    }
+
+    /// The value closest to `ohms` as a significand from [`ESeries::significands`]
+    /// and the power of ten it is multiplied by.
+    pub(crate) fn nearest_parts(self, ohms: f64) -> (u32, i32) {
+        let decade = ohms.log10().floor() as i32;
+        let error =
+            |&(significand, exponent): &(u32, i32)| (scaled(significand, exponent) - ohms).abs();
+        // rounding in log10 can put `ohms` a decade off, so look at both neighbours
+        (decade - 3..=decade - 1)
+            .flat_map(|exponent| {
+                self.significands()
+                    .into_iter()
+                    .map(move |significand| (significand, exponent))
+            })
+            .min_by(|a, b| error(a).total_cmp(&error(b)))
+            .unwrap()
+    }
+
+    /// The standard value closest to `ohms`, which must be positive.
+    pub fn nearest(self, ohms: f64) -> f64 {
+        let (significand, exponent) = self.nearest_parts(ohms);
+        scaled(significand, exponent)
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::resistance::scaled;
+use crate::{ESeries, Resistance};
+
+/// How an SMD resistor is marked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmdFormat {
+    /// Two digits and a multiplier, e.g. `472`, or `4R7` below 10Ω. ±5%.
+    ThreeDigit,
+    /// Three digits and a multiplier, e.g. `4701`, or `47R0` below 100Ω. ±1%.
+    FourDigit,
+    /// The index of an E96 value and a multiplier letter, e.g. `01C`. ±1%.
+    Eia96,
+}
+
+impl SmdFormat {
+    fn tolerance(self) -> f64 {
+        match self {
+            SmdFormat::ThreeDigit => 5.0,
+            SmdFormat::FourDigit | SmdFormat::Eia96 => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SmdError {
+    InvalidCode(String),
+    /// The value has too many significant digits for the format, or isn't
+    /// one of its values at all.
+    NotRepresentable {
+        ohms: f64,
+        format: SmdFormat,
+    },
+}
+
+impl fmt::Display for SmdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SmdError::InvalidCode(code) => write!(f, "invalid SMD code `{}`", code),
+            SmdError::NotRepresentable { ohms, format } => {
+                write!(f, "{}Ω has no {:?} code", ohms, format)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SmdError {}
+
+/// EIA-96 multiplier letters, from 10^-3 up.
+const EIA96_MULTIPLIERS: [char; 9] = ['Z', 'Y', 'X', 'A', 'B', 'C', 'D', 'E', 'F'];
+
+fn eia96_exponent(letter: char) -> Option<i32> {
+    // S and H are sometimes used instead of X and B
+    let letter = match letter {
+        'S' => 'X',
+        'H' => 'B',
+        letter => letter,
+    };
+    let position = EIA96_MULTIPLIERS.iter().position(|&l| l == letter)?;
+    Some(position as i32 - 3)
+}
+
+/// Reads an SMD marking in any of the [`SmdFormat`]s.
+///
+/// `R` always marks the decimal point, so it isn't accepted as an EIA-96
+/// multiplier. `0` and `000` are zero-ohm jumpers.
+pub fn parse_smd(code: &str) -> Result<Resistance, SmdError> {
+    let invalid = || SmdError::InvalidCode(code.to_string());
+    let upper = code.to_ascii_uppercase();
+    let chars: Vec<char> = upper.chars().collect();
+    let format = match chars.len() {
+        1 | 3 => SmdFormat::ThreeDigit,
+        4 => SmdFormat::FourDigit,
+        _ => return Err(invalid()),
+    };
+    let digits = |chars: &[char]| -> Option<u32> {
+        match chars.iter().all(char::is_ascii_digit) {
+            true => chars.iter().collect::<String>().parse().ok(),
+            false => None,
+        }
+    };
+    let ohms = if upper == "0" {
+        0.0
+    } else if upper.matches('R').count() == 1 && chars.len() > 1 {
+        let decimal = upper.replacen('R', ".", 1);
+        match decimal.chars().all(|c| c == '.' || c.is_ascii_digit()) {
+            true => decimal.parse().map_err(|_| invalid())?,
+            false => return Err(invalid()),
+        }
+    } else if let Some(value) = digits(&chars).filter(|_| chars.len() > 1) {
+        scaled(value / 10, (value % 10) as i32)
+    } else if chars.len() == 3 {
+        let index = digits(&chars[..2]).ok_or_else(invalid)?;
+        let exponent = eia96_exponent(chars[2]).ok_or_else(invalid)?;
+        let significand = match index {
+            1..=96 => ESeries::E96.significands()[index as usize - 1],
+            _ => return Err(invalid()),
+        };
+        return Ok(Resistance {
+            ohms: scaled(significand, exponent),
+            tolerance: SmdFormat::Eia96.tolerance(),
+            temperature_coefficient: None,
+        });
+    } else {
+        return Err(invalid());
+    };
+    Ok(Resistance {
+        ohms,
+        tolerance: format.tolerance(),
+        temperature_coefficient: None,
+    })
+}
+
+/// Splits `ohms` into a significand of exactly `digits` digits and a power of ten.
+fn split(ohms: f64, digits: u32) -> Option<(u32, i32)> {
+    let decade = ohms.log10().floor() as i32;
+    let smallest = 10u32.pow(digits - 1);
+    (decade - digits as i32..=decade + 2 - digits as i32).find_map(|exponent| {
+        let significand = (ohms / scaled(1, exponent)).round();
+        let exact = (scaled(significand as u32, exponent) - ohms).abs() <= ohms * 1e-9;
+        let in_range = significand >= smallest as f64 && significand < (smallest * 10) as f64;
+        match exact && in_range {
+            true => Some((significand as u32, exponent)),
+            false => None,
+        }
+    })
+}
+
+/// Marks `resistance` in `format`. Its tolerance isn't part of the code.
+pub fn smd_code(resistance: &Resistance, format: SmdFormat) -> Result<String, SmdError> {
+    let ohms = resistance.ohms;
+    let unrepresentable = || SmdError::NotRepresentable { ohms, format };
+    if !(ohms >= 0.0 && ohms.is_finite()) {
+        return Err(unrepresentable());
+    }
+    let width = match format {
+        SmdFormat::ThreeDigit => 3,
+        SmdFormat::FourDigit => 4,
+        SmdFormat::Eia96 => {
+            let (significand, exponent) = split(ohms, 3).ok_or_else(unrepresentable)?;
+            let index = ESeries::E96
+                .significands()
+                .iter()
+                .position(|&value| value == significand)
+                .ok_or_else(unrepresentable)?;
+            let letter = usize::try_from(exponent + 3)
+                .ok()
+                .and_then(|position| EIA96_MULTIPLIERS.get(position))
+                .ok_or_else(unrepresentable)?;
+            return Ok(format!("{:02}{}", index + 1, letter));
+        }
+    };
+    if ohms == 0.0 {
+        return Ok("0".repeat(width));
+    }
+    // e.g. 10Ω is `100` in three digits and 100Ω is `1000` in four
+    let code = match ohms >= 10f64.powi(width as i32 - 2) {
+        true => match split(ohms, width as u32 - 1) {
+            Some((significand, exponent @ 0..=9)) => format!("{}{}", significand, exponent),
+            _ => return Err(unrepresentable()),
+        },
+        false => {
+            let whole_digits = match ohms < 1.0 {
+                true => 0,
+                false => ohms.log10().floor() as usize + 1,
+            };
+            let decimal = format!("{:.*}", width - 1 - whole_digits, ohms);
+            decimal
+                .strip_prefix('0')
+                .unwrap_or(&decimal)
+                .replace('.', "R")
+        }
+    };
+    // rounding in the R notation can lose digits
+    match parse_smd(&code) {
+        Ok(parsed) if (parsed.ohms - ohms).abs() <= ohms * 1e-9 => Ok(code),
+        _ => Err(unrepresentable()),
+    }
+}
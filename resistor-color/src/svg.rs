@@ -0,0 +1,174 @@
+use std::fmt::Write;
+
+use enum_iterator::all;
+use int_enum::IntEnum;
+
+use crate::{decode, ResistorColor};
+
+/// The fill used for each band color, and whether bands get a text label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    fills: Vec<String>,
+    labels: bool,
+}
+
+impl Palette {
+    fn from_fills(fill: impl Fn(ResistorColor) -> &'static str) -> Palette {
+        Palette {
+            fills: all::<ResistorColor>()
+                .map(|color| fill(color).to_string())
+                .collect(),
+            labels: false,
+        }
+    }
+
+    /// The usual band colors.
+    pub fn standard() -> Palette {
+        Palette::from_fills(|color| match color {
+            ResistorColor::Black => "#000000",
+            ResistorColor::Brown => "#8b4513",
+            ResistorColor::Red => "#ff0000",
+            ResistorColor::Orange => "#ffa500",
+            ResistorColor::Yellow => "#ffff00",
+            ResistorColor::Green => "#008000",
+            ResistorColor::Blue => "#0000ff",
+            ResistorColor::Violet => "#8a2be2",
+            ResistorColor::Grey => "#808080",
+            ResistorColor::White => "#ffffff",
+            ResistorColor::Gold => "#d4af37",
+            ResistorColor::Silver => "#c0c0c0",
+            ResistorColor::Pink => "#ff69b4",
+        })
+    }
+
+    /// Hues from the Okabe-Ito palette where there is one, with every band
+    /// labelled by its [`ResistorColor::iec_code`], since no palette keeps
+    /// thirteen colors apart for everyone.
+    pub fn color_blind() -> Palette {
+        Palette::from_fills(|color| match color {
+            ResistorColor::Black => "#000000",
+            ResistorColor::Brown => "#7f4a1f",
+            ResistorColor::Red => "#d55e00",
+            ResistorColor::Orange => "#e69f00",
+            ResistorColor::Yellow => "#f0e442",
+            ResistorColor::Green => "#009e73",
+            ResistorColor::Blue => "#0072b2",
+            ResistorColor::Violet => "#cc79a7",
+            ResistorColor::Grey => "#999999",
+            ResistorColor::White => "#ffffff",
+            ResistorColor::Gold => "#b8860b",
+            ResistorColor::Silver => "#d9d9d9",
+            ResistorColor::Pink => "#f4b6c2",
+        })
+        .with_labels(true)
+    }
+
+    /// Overrides the fill of one color. `fill` is any SVG paint, e.g. `"#123456"`.
+    pub fn with_fill(mut self, color: ResistorColor, fill: &str) -> Palette {
+        self.fills[color.int_value() as usize] = fill.to_string();
+        self
+    }
+
+    pub fn with_labels(mut self, labels: bool) -> Palette {
+        self.labels = labels;
+        self
+    }
+
+    pub fn fill(&self, color: ResistorColor) -> &str {
+        &self.fills[color.int_value() as usize]
+    }
+
+    pub fn labels(&self) -> bool {
+        self.labels
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::standard()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const WIDTH: u32 = 240;
+const BODY_START: u32 = 40;
+const BODY_END: u32 = 200;
+const BAND_WIDTH: u32 = 10;
+const BAND_STEP: u32 = 18;
+
+/// Draws a resistor with `bands`, read left to right, as a standalone SVG.
+///
+/// As on real parts, the tolerance band (and the temperature band after it)
+/// sits apart at the right end for 4 to 6 bands. Decodable sequences get
+/// their value as the drawing's title.
+pub fn render_svg(bands: &[ResistorColor], palette: &Palette) -> String {
+    let trailing = match bands.len() {
+        4 | 5 => 1,
+        6 => 2,
+        _ => 0,
+    };
+    let leading = bands.len() - trailing;
+    let positions = (0..leading)
+        .map(|i| BODY_START + 14 + i as u32 * BAND_STEP)
+        .chain(
+            (0..trailing)
+                .rev()
+                .map(|i| BODY_END - 14 - BAND_WIDTH - i as u32 * BAND_STEP),
+        );
+    let height = match palette.labels {
+        true => 80,
+        false => 60,
+    };
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = WIDTH,
+        h = height
+    )
+    .unwrap();
+    if let Ok(resistance) = decode(bands) {
+        writeln!(svg, "  <title>{}</title>", resistance).unwrap();
+    }
+    writeln!(
+        svg,
+        r##"  <line x1="0" y1="30" x2="{}" y2="30" stroke="#888888" stroke-width="4"/>"##,
+        WIDTH
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r##"  <rect x="{}" y="10" width="{}" height="40" rx="10" fill="#e6cfa1" stroke="#555555"/>"##,
+        BODY_START,
+        BODY_END - BODY_START
+    )
+    .unwrap();
+    for (&color, x) in bands.iter().zip(positions) {
+        writeln!(
+            svg,
+            r#"  <rect x="{}" y="10" width="{}" height="40" fill="{}"/>"#,
+            x,
+            BAND_WIDTH,
+            escape(palette.fill(color))
+        )
+        .unwrap();
+        if palette.labels {
+            writeln!(
+                svg,
+                r#"  <text x="{}" y="70" font-family="sans-serif" font-size="10" text-anchor="middle">{}</text>"#,
+                x + BAND_WIDTH / 2,
+                color.iec_code()
+            )
+            .unwrap();
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
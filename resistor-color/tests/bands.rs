@@ -0,0 +1,95 @@
+use resistor_color::ResistorColor::*;
+use resistor_color::{decode, DecodeError, Resistance};
+
+#[test]
+fn test_three_bands() {
+    assert_eq!(
+        decode(&[Yellow, Violet, Brown]),
+        Ok(Resistance {
+            ohms: 470.0,
+            tolerance: 20.0,
+            temperature_coefficient: None,
+        })
+    );
+}
+
+#[test]
+fn test_four_bands() {
+    let resistance = decode(&[Brown, Black, Red, Gold]).unwrap();
+    assert_eq!(resistance.ohms, 1000.0);
+    assert_eq!(resistance.tolerance, 5.0);
+    assert_eq!(resistance.to_string(), "1kΩ ±5%");
+}
+
+#[test]
+fn test_five_bands() {
+    let resistance = decode(&[Yellow, Violet, Black, Brown, Brown]).unwrap();
+    assert_eq!(resistance.ohms, 4700.0);
+    assert_eq!(resistance.to_string(), "4.7kΩ ±1%");
+}
+
+#[test]
+fn test_six_bands() {
+    let resistance = decode(&[Red, Red, Black, Orange, Violet, Brown]).unwrap();
+    assert_eq!(resistance.temperature_coefficient, Some(100));
+    assert_eq!(resistance.to_string(), "220kΩ ±0.1% 100ppm/K");
+}
+
+#[test]
+fn test_fractional_multipliers() {
+    assert_eq!(decode(&[Yellow, Violet, Gold, Gold]).unwrap().ohms, 4.7);
+    assert_eq!(decode(&[Red, Red, Silver, Silver]).unwrap().ohms, 0.22);
+    assert_eq!(
+        decode(&[Brown, Black, Black, Pink, Brown])
+            .unwrap()
+            .to_string(),
+        "0.1Ω ±1%"
+    );
+}
+
+#[test]
+fn test_large_values() {
+    assert_eq!(
+        decode(&[Brown, Black, Blue, Silver]).unwrap().to_string(),
+        "10MΩ ±10%"
+    );
+    assert_eq!(
+        decode(&[Red, Black, White, Gold]).unwrap().to_string(),
+        "20GΩ ±5%"
+    );
+}
+
+#[test]
+fn test_band_count() {
+    assert_eq!(decode(&[Brown, Black]), Err(DecodeError::BandCount(2)));
+    assert_eq!(decode(&[Brown; 7]), Err(DecodeError::BandCount(7)));
+}
+
+#[test]
+fn test_invalid_band_positions() {
+    assert_eq!(
+        decode(&[Gold, Black, Red, Gold]),
+        Err(DecodeError::InvalidBand {
+            position: 0,
+            color: Gold
+        })
+    );
+    assert_eq!(
+        decode(&[Brown, Black, Red, Pink]),
+        Err(DecodeError::InvalidBand {
+            position: 3,
+            color: Pink
+        })
+    );
+    assert_eq!(
+        decode(&[Brown, Black, Black, Red, Brown, White]),
+        Err(DecodeError::InvalidBand {
+            position: 5,
+            color: White
+        })
+    );
+    assert_eq!(
+        decode(&[Brown, Black, Red, Black]).unwrap_err().to_string(),
+        "Black is not valid at band 3"
+    );
+}
@@ -0,0 +1,94 @@
+use std::process::{Command, Output};
+
+fn resistor(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_resistor"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = resistor(args);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_decode() {
+    assert_eq!(
+        stdout(&["decode", "brown", "black", "red", "gold"]),
+        "1kΩ ±5%\n"
+    );
+    assert_eq!(stdout(&["decode", "Y-V-K-N-N"]), "4.7kΩ ±1%\n");
+    assert_eq!(stdout(&["decode", "4R7"]), "4.7Ω ±5%\n");
+}
+
+#[test]
+fn test_decode_errors() {
+    let output = resistor(&["decode", "brown", "blak", "red", "gold"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: unknown color `blak`\n"
+    );
+    let output = resistor(&["decode", "gold", "black", "red"]);
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: Gold is not valid at band 0\n"
+    );
+    assert_eq!(resistor(&["decode"]).status.code(), Some(2));
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        stdout(&["encode", "4k7", "--bands", "5"]),
+        "Yellow Violet Black Brown Brown\n"
+    );
+    assert_eq!(stdout(&["encode", "4.7k"]), "Yellow Violet Red Gold\n");
+    assert_eq!(
+        stdout(&["encode", "4k8", "--series", "e12", "--tolerance", "10%"]),
+        "Yellow Violet Red Silver\nsnapped to 4.7kΩ ±10% (-2.08%)\n"
+    );
+    assert_eq!(
+        String::from_utf8(resistor(&["encode", "4k7x"]).stderr).unwrap(),
+        "error: invalid value `4k7x`\n"
+    );
+}
+
+#[test]
+fn test_list() {
+    let output = stdout(&["list"]);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 14);
+    assert!(lines[0].starts_with("color   iec short digit multiplier"));
+    assert!(lines[1].starts_with("Black   BK  K     0     10^0"));
+    assert!(lines[11].starts_with("Gold    GD  D     -     10^-1      ±5%"));
+}
+
+#[test]
+fn test_json() {
+    let decoded: serde_json::Value = serde_json::from_str(&stdout(&[
+        "--json", "decode", "brown", "black", "red", "gold",
+    ]))
+    .unwrap();
+    assert_eq!(decoded["ohms"], 1000.0);
+    assert_eq!(decoded["tolerance"], 5.0);
+    assert_eq!(decoded["text"], "1kΩ ±5%");
+
+    let encoded: serde_json::Value =
+        serde_json::from_str(&stdout(&["encode", "4k7", "--bands", "5", "--json"])).unwrap();
+    assert_eq!(
+        encoded["bands"],
+        serde_json::json!(["Yellow", "Violet", "Black", "Brown", "Brown"])
+    );
+    assert_eq!(encoded["error"], 0.0);
+
+    let listed: serde_json::Value = serde_json::from_str(&stdout(&["--json", "list"])).unwrap();
+    assert_eq!(listed.as_array().unwrap().len(), 13);
+    assert_eq!(listed[0]["name"], "Black");
+    assert_eq!(listed[0]["iec_code"], "BK");
+    assert_eq!(listed[0]["short_code"], "K");
+    assert_eq!(listed[0]["digit"], 0);
+    assert_eq!(listed[12]["digit"], serde_json::Value::Null);
+}
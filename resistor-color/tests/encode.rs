@@ -0,0 +1,110 @@
+use resistor_color::ResistorColor::*;
+use resistor_color::{decode, encode, ESeries, EncodeError, Resistance};
+
+fn target(ohms: f64, tolerance: f64) -> Resistance {
+    Resistance {
+        ohms,
+        tolerance,
+        temperature_coefficient: None,
+    }
+}
+
+#[test]
+fn test_series_sizes() {
+    let sizes: Vec<usize> = [
+        ESeries::E6,
+        ESeries::E12,
+        ESeries::E24,
+        ESeries::E48,
+        ESeries::E96,
+        ESeries::E192,
+    ]
+    .iter()
+    .map(|series| series.significands().len())
+    .collect();
+    assert_eq!(sizes, vec![6, 12, 24, 48, 96, 192]);
+    assert_eq!(
+        ESeries::E6.significands(),
+        vec![100, 150, 220, 330, 470, 680]
+    );
+    assert_eq!(ESeries::E48.significands()[..3], [100, 105, 110]);
+}
+
+#[test]
+fn test_nearest() {
+    assert_eq!(ESeries::E12.nearest(4800.0), 4700.0);
+    assert_eq!(ESeries::E96.nearest(4800.0), 4750.0);
+    assert_eq!(ESeries::E6.nearest(9600.0), 10000.0);
+    assert_eq!(ESeries::E24.nearest(1000.0), 1000.0);
+    assert_eq!(ESeries::E24.nearest(0.5), 0.51);
+}
+
+#[test]
+fn test_encode_exact_value() {
+    let encoding = encode(target(4700.0, 5.0), ESeries::E12, 4).unwrap();
+    assert_eq!(encoding.bands, vec![Yellow, Violet, Red, Gold]);
+    assert_eq!(encoding.resistance.to_string(), "4.7kΩ ±5%");
+    assert_eq!(encoding.error, 0.0);
+}
+
+#[test]
+fn test_encode_snaps_and_reports_error() {
+    let encoding = encode(target(4800.0, 5.0), ESeries::E12, 4).unwrap();
+    assert_eq!(encoding.resistance.ohms, 4700.0);
+    assert!((encoding.error - -2.0833).abs() < 1e-3);
+
+    let encoding = encode(target(4800.0, 1.0), ESeries::E96, 5).unwrap();
+    assert_eq!(encoding.bands, vec![Yellow, Violet, Green, Brown, Brown]);
+    assert!((encoding.error - -1.0417).abs() < 1e-3);
+}
+
+#[test]
+fn test_encode_crosses_decade() {
+    let encoding = encode(target(9600.0, 10.0), ESeries::E6, 4).unwrap();
+    assert_eq!(encoding.bands, vec![Brown, Black, Orange, Silver]);
+}
+
+#[test]
+fn test_encode_fractional_values() {
+    let encoding = encode(target(0.47, 5.0), ESeries::E24, 4).unwrap();
+    assert_eq!(encoding.bands, vec![Yellow, Violet, Silver, Gold]);
+    let encoding = encode(target(0.1, 1.0), ESeries::E24, 5).unwrap();
+    assert_eq!(encoding.bands, vec![Brown, Black, Black, Pink, Brown]);
+}
+
+#[test]
+fn test_encode_round_trips_every_value() {
+    for series in [ESeries::E24, ESeries::E192] {
+        for significand in series.significands() {
+            let ohms = significand as f64 * 100.0;
+            let encoding = encode(target(ohms, 1.0), series, 5).unwrap();
+            assert_eq!(decode(&encoding.bands), Ok(encoding.resistance));
+            assert_eq!(encoding.resistance.ohms, ohms);
+        }
+    }
+}
+
+#[test]
+fn test_encode_errors() {
+    assert_eq!(
+        encode(target(4700.0, 5.0), ESeries::E12, 3),
+        Err(EncodeError::BandCount(3))
+    );
+    assert_eq!(
+        encode(target(0.0, 5.0), ESeries::E12, 4),
+        Err(EncodeError::OutOfRange(0.0))
+    );
+    assert_eq!(
+        encode(target(1e12, 5.0), ESeries::E12, 4),
+        Err(EncodeError::OutOfRange(1e12))
+    );
+    assert_eq!(
+        encode(target(4700.0, 20.0), ESeries::E12, 4),
+        Err(EncodeError::Tolerance(20.0))
+    );
+    assert_eq!(
+        encode(target(4800.0, 1.0), ESeries::E96, 4),
+        Err(EncodeError::Digits(4750.0))
+    );
+    assert!(encode(target(1000.0, 1.0), ESeries::E48, 4).is_ok());
+}
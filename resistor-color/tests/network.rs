@@ -0,0 +1,130 @@
+use resistor_color::ResistorColor::*;
+use resistor_color::{solve, ESeries, EncodeError, Network, Resistance};
+
+fn target(ohms: f64) -> Resistance {
+    Resistance {
+        ohms,
+        tolerance: 5.0,
+        temperature_coefficient: None,
+    }
+}
+
+#[test]
+fn test_network_values() {
+    let network = Network::Series(vec![
+        Network::Part(1000.0),
+        Network::Parallel(vec![Network::Part(2000.0), Network::Part(2000.0)]),
+    ]);
+    assert_eq!(network.ohms(), 2000.0);
+    assert_eq!(network.parts(), vec![1000.0, 2000.0, 2000.0]);
+    assert_eq!(network.part_count(), 3);
+    assert_eq!(network.to_string(), "1kΩ + (2kΩ ∥ 2kΩ)");
+}
+
+#[test]
+fn test_standard_value_needs_one_part() {
+    let solutions = solve(target(4700.0), ESeries::E12, 3).unwrap();
+    assert_eq!(solutions[0].network, Network::Part(4700.0));
+    assert_eq!(solutions[0].error, 0.0);
+    assert_eq!(solutions[0].bands, vec![vec![Yellow, Violet, Red, Gold]]);
+}
+
+#[test]
+fn test_series_pair() {
+    // 2kΩ isn't in E6, but 1kΩ twice is
+    let solutions = solve(target(2000.0), ESeries::E6, 2).unwrap();
+    let best = &solutions[0];
+    assert_eq!(
+        best.network,
+        Network::Series(vec![Network::Part(1000.0), Network::Part(1000.0)])
+    );
+    assert_eq!(best.error, 0.0);
+    assert_eq!(best.bands.len(), 2);
+    assert_eq!(best.bands[0], vec![Brown, Black, Red, Gold]);
+}
+
+#[test]
+fn test_parallel_pair() {
+    let solutions = solve(target(500.0), ESeries::E6, 2).unwrap();
+    assert_eq!(
+        solutions[0].network,
+        Network::Parallel(vec![Network::Part(1000.0), Network::Part(1000.0)])
+    );
+}
+
+#[test]
+fn test_ranked_by_error_then_part_count() {
+    let solutions = solve(target(1234.0), ESeries::E12, 3).unwrap();
+    assert!(solutions.len() > 1 && solutions.len() <= 10);
+    for pair in solutions.windows(2) {
+        let (a, b) = (pair[0].error.abs(), pair[1].error.abs());
+        assert!(
+            a < b + 1e-4
+                || (a - b).abs() < 1e-4
+                    && pair[0].network.part_count() <= pair[1].network.part_count()
+        );
+    }
+    assert!(solutions[0].error.abs() < 0.1);
+    assert!(solutions.iter().all(|s| s.network.part_count() <= 3));
+    assert!(solutions
+        .iter()
+        .all(|s| s.bands.len() == s.network.part_count()));
+}
+
+#[test]
+fn test_more_parts_get_closer() {
+    let error = |max_parts| {
+        solve(target(1234.0), ESeries::E6, max_parts).unwrap()[0]
+            .error
+            .abs()
+    };
+    assert!(error(2) < error(1));
+    assert!(error(3) <= error(2));
+}
+
+#[test]
+fn test_no_duplicate_networks() {
+    let solutions = solve(target(3000.0), ESeries::E6, 2).unwrap();
+    for (i, a) in solutions.iter().enumerate() {
+        assert!(solutions[i + 1..].iter().all(|b| b.network != a.network));
+    }
+}
+
+#[test]
+fn test_invalid_target() {
+    assert_eq!(
+        solve(target(0.0), ESeries::E12, 2),
+        Err(EncodeError::OutOfRange(0.0))
+    );
+    let mut loose = target(1000.0);
+    loose.tolerance = 20.0;
+    assert_eq!(
+        solve(loose, ESeries::E6, 2),
+        Err(EncodeError::Tolerance(20.0))
+    );
+    assert_eq!(solve(target(1000.0), ESeries::E6, 0), Ok(vec![]));
+}
+
+#[test]
+fn test_two_parallel_pairs_in_series() {
+    // (1kΩ ∥ 1.5kΩ) + (2.2kΩ ∥ 3.3kΩ), which no 3 parts of E6 make
+    let best = |max_parts| solve(target(1920.0), ESeries::E6, max_parts).unwrap()[0].clone();
+    assert!(best(3).error.abs() > 0.01);
+    let four = best(4);
+    assert_eq!(
+        four.network,
+        Network::Series(vec![
+            Network::Parallel(vec![Network::Part(1000.0), Network::Part(1500.0)]),
+            Network::Parallel(vec![Network::Part(2200.0), Network::Part(3300.0)]),
+        ])
+    );
+    assert!(four.error.abs() < 1e-9);
+}
+
+#[test]
+fn test_many_parts() {
+    let solutions = solve(target(1234.0), ESeries::E12, 12).unwrap();
+    assert!(solutions.iter().all(|s| s.network.part_count() <= 12));
+    let three = solve(target(1234.0), ESeries::E12, 3).unwrap();
+    assert!(solutions[0].error.abs() <= three[0].error.abs());
+}
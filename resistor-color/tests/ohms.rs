@@ -0,0 +1,27 @@
+use resistor_color::parse_ohms;
+
+#[test]
+fn test_prefixes() {
+    assert_eq!(parse_ohms("4700"), Some(4700.0));
+    assert_eq!(parse_ohms("4.7k"), Some(4700.0));
+    assert_eq!(parse_ohms("4k7"), Some(4700.0));
+    assert_eq!(parse_ohms("4K7Ω"), Some(4700.0));
+    assert_eq!(parse_ohms("R47"), Some(0.47));
+    assert_eq!(parse_ohms("2R2"), Some(2.2));
+    assert_eq!(parse_ohms("1M"), Some(1e6));
+    assert_eq!(parse_ohms("1m"), Some(1e6));
+    assert_eq!(parse_ohms("10ohms"), Some(10.0));
+    assert_eq!(parse_ohms("4.7k7"), None);
+    assert_eq!(parse_ohms("4x7"), None);
+    assert_eq!(parse_ohms("k"), None);
+    assert_eq!(parse_ohms("-5"), None);
+}
+
+#[test]
+fn test_whitespace_before_unit() {
+    assert_eq!(parse_ohms("10 ohms"), Some(10.0));
+    assert_eq!(parse_ohms("4.7k Ω"), Some(4700.0));
+    assert_eq!(parse_ohms(" 220 "), Some(220.0));
+    assert_eq!(parse_ohms("4 k7"), None);
+    assert_eq!(parse_ohms("10 000"), None);
+}
@@ -0,0 +1,84 @@
+use enum_iterator::all;
+use resistor_color::ResistorColor::*;
+use resistor_color::{decode, parse_bands, ParseColorError, ResistorColor};
+
+#[test]
+fn test_display_round_trips() {
+    for color in all::<ResistorColor>() {
+        assert_eq!(color.to_string().parse(), Ok(color));
+    }
+}
+
+#[test]
+fn test_case_insensitive_names() {
+    assert_eq!("BROWN".parse(), Ok(Brown));
+    assert_eq!("sIlVeR".parse(), Ok(Silver));
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!("gray".parse(), Ok(Grey));
+    assert_eq!("Purple".parse(), Ok(Violet));
+}
+
+#[test]
+fn test_single_letter_codes() {
+    let colors: Result<Vec<ResistorColor>, _> = "KNROYGUVAWDSP"
+        .chars()
+        .map(|code| code.to_string().parse())
+        .collect();
+    assert_eq!(colors.unwrap(), all::<ResistorColor>().collect::<Vec<_>>());
+    assert_eq!("u".parse(), Ok(Blue));
+}
+
+#[test]
+fn test_iec_codes() {
+    let colors: Result<Vec<ResistorColor>, _> = "BK BN RD OG YE GN BU VT GY WH GD SR PK"
+        .split(' ')
+        .map(str::parse)
+        .collect();
+    assert_eq!(colors.unwrap(), all::<ResistorColor>().collect::<Vec<_>>());
+    assert_eq!("gy".parse(), Ok(Grey));
+    assert_eq!(
+        parse_bands("BN-BK-RD-GD").unwrap(),
+        [Brown, Black, Red, Gold]
+    );
+}
+
+#[test]
+fn test_unknown_color() {
+    assert_eq!(
+        "teal".parse::<ResistorColor>(),
+        Err(ParseColorError {
+            token: "teal".to_string()
+        })
+    );
+    assert!("".parse::<ResistorColor>().is_err());
+}
+
+#[test]
+fn test_parse_bands() {
+    assert_eq!(
+        parse_bands("brown-black-red-gold"),
+        Ok(vec![Brown, Black, Red, Gold])
+    );
+    assert_eq!(
+        parse_bands("Yellow, Violet, Black, Brown, Brown"),
+        Ok(vec![Yellow, Violet, Black, Brown, Brown])
+    );
+    assert_eq!(parse_bands("y v r d"), Ok(vec![Yellow, Violet, Red, Gold]));
+    assert_eq!(parse_bands(""), Ok(vec![]));
+    assert_eq!(
+        decode(&parse_bands("brown-black-red-gold").unwrap())
+            .unwrap()
+            .to_string(),
+        "1kΩ ±5%"
+    );
+}
+
+#[test]
+fn test_parse_bands_names_unknown_token() {
+    let err = parse_bands("brown-blak-red-gold").unwrap_err();
+    assert_eq!(err.token, "blak");
+    assert_eq!(err.to_string(), "unknown color `blak`");
+}
@@ -0,0 +1,143 @@
+use resistor_color::ResistorColor::*;
+use resistor_color::{decode, parse_smd, smd_code, Resistance, SmdError, SmdFormat};
+
+fn ohms(code: &str) -> f64 {
+    parse_smd(code).unwrap().ohms
+}
+
+fn resistance(ohms: f64) -> Resistance {
+    Resistance {
+        ohms,
+        tolerance: 1.0,
+        temperature_coefficient: None,
+    }
+}
+
+#[test]
+fn test_three_digit_codes() {
+    assert_eq!(ohms("472"), 4700.0);
+    assert_eq!(ohms("100"), 10.0);
+    assert_eq!(ohms("105"), 1_000_000.0);
+    assert_eq!(parse_smd("472").unwrap().tolerance, 5.0);
+}
+
+#[test]
+fn test_four_digit_codes() {
+    assert_eq!(ohms("4701"), 4700.0);
+    assert_eq!(ohms("1000"), 100.0);
+    assert_eq!(parse_smd("4701").unwrap().tolerance, 1.0);
+}
+
+#[test]
+fn test_r_notation() {
+    assert_eq!(ohms("R47"), 0.47);
+    assert_eq!(ohms("4R7"), 4.7);
+    assert_eq!(ohms("r05"), 0.05);
+    assert_eq!(ohms("47R0"), 47.0);
+    assert_eq!(ohms("0R22"), 0.22);
+}
+
+#[test]
+fn test_eia96_codes() {
+    assert_eq!(ohms("01C"), 10_000.0);
+    assert_eq!(ohms("68X"), 49.9);
+    assert_eq!(ohms("96F"), 97_600_000.0);
+    assert_eq!(ohms("01Z"), 0.1);
+    assert_eq!(ohms("30H"), 2000.0);
+}
+
+#[test]
+fn test_zero_ohm_jumpers() {
+    assert_eq!(ohms("0"), 0.0);
+    assert_eq!(ohms("000"), 0.0);
+}
+
+#[test]
+fn test_same_value_as_bands() {
+    assert_eq!(
+        parse_smd("4701").unwrap(),
+        decode(&[Yellow, Violet, Black, Brown, Brown]).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_codes() {
+    for code in ["", "47", "47000", "4R7R", "97A", "00A", "01Q", "4x7", "R"] {
+        assert_eq!(
+            parse_smd(code),
+            Err(SmdError::InvalidCode(code.to_string())),
+            "{}",
+            code
+        );
+    }
+}
+
+#[test]
+fn test_generate_codes() {
+    assert_eq!(
+        smd_code(&resistance(4700.0), SmdFormat::ThreeDigit),
+        Ok("472".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(4.7), SmdFormat::ThreeDigit),
+        Ok("4R7".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(0.05), SmdFormat::ThreeDigit),
+        Ok("R05".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(4700.0), SmdFormat::FourDigit),
+        Ok("4701".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(47.0), SmdFormat::FourDigit),
+        Ok("47R0".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(10_000.0), SmdFormat::Eia96),
+        Ok("01C".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(49.9), SmdFormat::Eia96),
+        Ok("68X".to_string())
+    );
+    assert_eq!(
+        smd_code(&resistance(0.0), SmdFormat::ThreeDigit),
+        Ok("000".to_string())
+    );
+}
+
+#[test]
+fn test_generate_round_trips() {
+    for code in [
+        "472", "4R7", "R47", "105", "100", "470", "4701", "47R0", "R470", "1000", "4700", "01C",
+        "68X", "96F",
+    ] {
+        let format = match code.len() {
+            4 => SmdFormat::FourDigit,
+            _ if code.ends_with(char::is_alphabetic) => SmdFormat::Eia96,
+            _ => SmdFormat::ThreeDigit,
+        };
+        assert_eq!(
+            smd_code(&parse_smd(code).unwrap(), format).as_deref(),
+            Ok(code),
+            "{}",
+            code
+        );
+    }
+}
+
+#[test]
+fn test_not_representable() {
+    assert_eq!(
+        smd_code(&resistance(4750.0), SmdFormat::ThreeDigit),
+        Err(SmdError::NotRepresentable {
+            ohms: 4750.0,
+            format: SmdFormat::ThreeDigit
+        })
+    );
+    assert!(smd_code(&resistance(4700.0), SmdFormat::Eia96).is_err());
+    assert!(smd_code(&resistance(1e12), SmdFormat::ThreeDigit).is_err());
+    assert!(smd_code(&resistance(0.001), SmdFormat::ThreeDigit).is_err());
+}
@@ -0,0 +1,79 @@
+use resistor_color::ResistorColor::*;
+use resistor_color::{render_svg, Palette, ResistorColor};
+
+/// The `x` and `fill` of every band rectangle, skipping the body.
+fn bands(svg: &str) -> Vec<(u32, String)> {
+    svg.lines()
+        .filter(|line| line.trim_start().starts_with("<rect") && !line.contains("rx="))
+        .map(|line| {
+            let attribute = |name: &str| {
+                let start = line.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+                line[start..].split('"').next().unwrap().to_string()
+            };
+            (attribute("x").parse().unwrap(), attribute("fill"))
+        })
+        .collect()
+}
+
+#[test]
+fn test_standalone_document() {
+    let svg = render_svg(&[Brown, Black, Red, Gold], &Palette::default());
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("<title>1kΩ ±5%</title>"));
+}
+
+#[test]
+fn test_band_fills_in_order() {
+    let svg = render_svg(&[Yellow, Violet, Red, Gold], &Palette::standard());
+    let fills: Vec<String> = bands(&svg).into_iter().map(|(_, fill)| fill).collect();
+    assert_eq!(fills, vec!["#ffff00", "#8a2be2", "#ff0000", "#d4af37"]);
+    assert!(!svg.contains("<text"));
+}
+
+#[test]
+fn test_tolerance_band_sits_apart() {
+    let xs = |colors: &[ResistorColor]| -> Vec<u32> {
+        bands(&render_svg(colors, &Palette::default()))
+            .into_iter()
+            .map(|(x, _)| x)
+            .collect()
+    };
+    assert_eq!(xs(&[Yellow, Violet, Brown]), vec![54, 72, 90]);
+    assert_eq!(xs(&[Brown, Black, Red, Gold]), vec![54, 72, 90, 176]);
+    assert_eq!(
+        xs(&[Red, Red, Black, Orange, Violet, Brown]),
+        vec![54, 72, 90, 108, 158, 176]
+    );
+}
+
+#[test]
+fn test_color_blind_palette_labels_bands() {
+    let svg = render_svg(&[Brown, Black, Red, Gold], &Palette::color_blind());
+    let labels: Vec<&str> = svg
+        .lines()
+        .filter_map(|line| line.strip_suffix("</text>"))
+        .map(|line| line.rsplit('>').next().unwrap())
+        .collect();
+    assert_eq!(labels, vec!["BN", "BK", "RD", "GD"]);
+    assert!(svg.contains("height=\"80\""));
+    assert_eq!(bands(&svg)[0].1, "#7f4a1f");
+}
+
+#[test]
+fn test_custom_fill() {
+    let palette = Palette::standard()
+        .with_fill(Gold, "url(#gold)")
+        .with_fill(Red, "\"><script>");
+    assert_eq!(palette.fill(Gold), "url(#gold)");
+    let svg = render_svg(&[Brown, Black, Red, Gold], &palette);
+    assert_eq!(bands(&svg)[3].1, "url(#gold)");
+    assert!(!svg.contains("<script>"));
+}
+
+#[test]
+fn test_undecodable_bands_have_no_title() {
+    let svg = render_svg(&[Gold, Gold], &Palette::default());
+    assert!(!svg.contains("<title>"));
+    assert_eq!(bands(&svg).len(), 2);
+}
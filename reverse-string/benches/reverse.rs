@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use reverse_string::{reverse, reverse_into, reverse_lines, reversed_graphemes};
+use unicode_segmentation::UnicodeSegmentation;
+
+fn log_line() -> String {
+    "2024-05-01T12:00:00Z INFO request handled path=/api/v1/items status=200 user=名前 \
+     latency=12ms 👍🏽 "
+        .repeat(64)
+}
+
+fn bench_reverse(c: &mut Criterion) {
+    let line = log_line();
+    let mut group = c.benchmark_group("reverse");
+    group.throughput(Throughput::Bytes(line.len() as u64));
+    // what `reverse` used to do: collect into a String that grows as it goes
+    group.bench_function("collect", |b| {
+        b.iter(|| black_box(&line).graphemes(true).rev().collect::<String>())
+    });
+    group.bench_function("reverse", |b| b.iter(|| reverse(black_box(&line))));
+    group.bench_function("reverse_into reused buffer", |b| {
+        let mut output = String::with_capacity(line.len());
+        b.iter(|| {
+            output.clear();
+            reverse_into(black_box(&line), &mut output).unwrap();
+        })
+    });
+    group.bench_function("reversed_graphemes count", |b| {
+        b.iter(|| reversed_graphemes(black_box(&line)).count())
+    });
+    group.finish();
+}
+
+fn bench_reverse_lines(c: &mut Criterion) {
+    let text = format!("{}\n", log_line()).repeat(100);
+    let mut group = c.benchmark_group("reverse_lines");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("reverse_lines", |b| {
+        let mut output = Vec::with_capacity(text.len());
+        b.iter(|| {
+            output.clear();
+            reverse_lines(black_box(text.as_bytes()), &mut output).unwrap();
+        })
+    });
+    group.bench_function("reverse whole text", |b| {
+        b.iter(|| reverse(black_box(&text)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_reverse, bench_reverse_lines);
+criterion_main!(benches);
@@ -1,5 +1,152 @@
-use unicode_segmentation::UnicodeSegmentation;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo, Level};
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+pub mod matching;
+
+pub use matching::{is_anagram, is_palindrome, MatchOptions, Normalization};
 
 pub fn reverse(input: &str) -> String {
-    input.graphemes(true).rev().collect()
+    let mut output = String::with_capacity(input.len());
+    reverse_into(input, &mut output).expect("writing to a String can't fail");
+    output
+}
+
+/// The grapheme clusters of a string, last first, borrowed from it.
+#[derive(Debug, Clone)]
+pub struct ReversedGraphemes<'a> {
+    graphemes: Graphemes<'a>,
+}
+
+impl<'a> Iterator for ReversedGraphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.graphemes.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.graphemes.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ReversedGraphemes<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.graphemes.next()
+    }
+}
+
+pub fn reversed_graphemes(input: &str) -> ReversedGraphemes<'_> {
+    ReversedGraphemes {
+        graphemes: input.graphemes(true),
+    }
+}
+
+/// Writes `input` reversed to `output` without allocating.
+pub fn reverse_into(input: &str, output: &mut impl fmt::Write) -> fmt::Result {
+    reversed_graphemes(input).try_for_each(|grapheme| output.write_str(grapheme))
+}
+
+/// Reverses every line of `reader` into `writer`, keeping the line order and
+/// line endings.
+///
+/// Only one line is held at a time, so memory is bounded by the longest line.
+/// Fails with [`io::ErrorKind::InvalidData`] on text that isn't UTF-8.
+pub fn reverse_lines(mut reader: impl BufRead, mut writer: impl io::Write) -> io::Result<()> {
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        let text = line.trim_end_matches(['\n', '\r']);
+        for grapheme in reversed_graphemes(text) {
+            writer.write_all(grapheme.as_bytes())?;
+        }
+        writer.write_all(&line.as_bytes()[text.len()..])?;
+        line.clear();
+    }
+    writer.flush()
+}
+
+/// Word segments are the ones with letters or digits in them; the rest are
+/// whitespace and punctuation, which stay where they are.
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+/// Reverses the order of the words, keeping each word as it is:
+/// `"Hello, world!"` becomes `"world, Hello!"`.
+pub fn reverse_words(input: &str) -> String {
+    let segments: Vec<&str> = input.split_word_bounds().collect();
+    let mut words = segments.iter().filter(|segment| is_word(segment)).rev();
+    segments
+        .iter()
+        .map(|&segment| match is_word(segment) {
+            true => *words.next().unwrap(),
+            false => segment,
+        })
+        .collect()
+}
+
+/// Reverses every word in place: `"Hello, world!"` becomes `"olleH, dlrow!"`.
+pub fn reverse_each_word(input: &str) -> String {
+    input
+        .split_word_bounds()
+        .map(|segment| match is_word(segment) {
+            true => reverse(segment),
+            false => segment.to_string(),
+        })
+        .collect()
+}
+
+/// The base direction of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+/// Reverses `input` as it is displayed, so that mixed left-to-right and
+/// right-to-left text reads back to front from right to left.
+///
+/// The result is in visual order, for showing where no bidi algorithm runs
+/// again, such as plain terminals. Each paragraph takes its direction from its
+/// first strong character. Grapheme clusters stay intact, so flags, skin-tone
+/// modifiers, keycaps and ZWJ sequences survive.
+pub fn reverse_visual(input: &str) -> String {
+    reverse_visual_in(input, None)
+}
+
+/// Like [`reverse_visual`], with every paragraph in `direction`, or
+/// detected from its text for `None`.
+pub fn reverse_visual_in(input: &str, direction: Option<Direction>) -> String {
+    let level = direction.map(|direction| match direction {
+        Direction::Ltr => Level::ltr(),
+        Direction::Rtl => Level::rtl(),
+    });
+    // split by grapheme, so that "\r\n" is one separator
+    let mut paragraphs = vec![];
+    let mut start = 0;
+    for (i, grapheme) in input.grapheme_indices(true) {
+        if grapheme.chars().any(|c| bidi_class(c) == BidiClass::B) {
+            paragraphs.push((&input[start..i], grapheme));
+            start = i + grapheme.len();
+        }
+    }
+    paragraphs.push((&input[start..], ""));
+
+    let mut output = String::with_capacity(input.len());
+    // like `reverse`, the last paragraph comes first
+    for (text, separator) in paragraphs.into_iter().rev() {
+        output.push_str(separator);
+        let bidi = BidiInfo::new(text, level);
+        let Some(paragraph) = bidi.paragraphs.first() else {
+            continue;
+        };
+        let levels = bidi.reordered_levels(paragraph, paragraph.range.clone());
+        let graphemes: Vec<(usize, &str)> = text.grapheme_indices(true).collect();
+        let grapheme_levels: Vec<Level> = graphemes.iter().map(|&(i, _)| levels[i]).collect();
+        let visual = BidiInfo::reorder_visual(&grapheme_levels);
+        output.extend(visual.iter().rev().map(|&i| graphemes[i].1));
+    }
+    output
 }
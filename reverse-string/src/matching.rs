@@ -0,0 +1,70 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::reversed_graphemes;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical composition, so `e` plus a combining acute matches `é`.
+    Nfc,
+    /// Compatibility decomposition, which also matches e.g. `ﬁ` with `fi`.
+    Nfkd,
+}
+
+/// How [`is_palindrome`] and [`is_anagram`] compare text. By default
+/// graphemes have to match exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MatchOptions {
+    /// Compares with full case folding, so `ß` matches `ss`.
+    pub ignore_case: bool,
+    /// Skips graphemes that aren't letters or digits.
+    pub ignore_punctuation: bool,
+    pub normalization: Option<Normalization>,
+}
+
+impl MatchOptions {
+    fn normalize(&self, text: &str) -> String {
+        match self.normalization {
+            Some(Normalization::Nfc) => text.nfc().collect(),
+            Some(Normalization::Nfkd) => text.nfkd().collect(),
+            None => text.to_string(),
+        }
+    }
+
+    fn canonical(&self, text: &str) -> String {
+        let text = self.normalize(text);
+        match self.ignore_case {
+            // folding can undo the normalization, so it runs again
+            true => self.normalize(&caseless::default_case_fold_str(&text)),
+            false => text,
+        }
+    }
+
+    fn keeps(&self, grapheme: &str) -> bool {
+        !self.ignore_punctuation || grapheme.chars().any(char::is_alphanumeric)
+    }
+}
+
+/// Whether `input` reads the same grapheme by grapheme in both directions.
+pub fn is_palindrome(input: &str, options: MatchOptions) -> bool {
+    let text = options.canonical(input);
+    let keep = |grapheme: &&str| options.keeps(grapheme);
+    text.graphemes(true)
+        .filter(keep)
+        .eq(reversed_graphemes(&text).filter(keep))
+}
+
+/// Whether `a` and `b` are made of the same graphemes.
+pub fn is_anagram(a: &str, b: &str, options: MatchOptions) -> bool {
+    let graphemes = |text: &str| {
+        let text = options.canonical(text);
+        let mut graphemes: Vec<String> = text
+            .graphemes(true)
+            .filter(|grapheme| options.keeps(grapheme))
+            .map(String::from)
+            .collect();
+        graphemes.sort();
+        graphemes
+    };
+    graphemes(a) == graphemes(b)
+}
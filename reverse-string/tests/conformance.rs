@@ -0,0 +1,130 @@
+//! Conformance tests against the Unicode test data in `tests/data`, see its README.
+
+use std::fs;
+use std::path::PathBuf;
+
+use reverse_string::*;
+use unicode_bidi::{bidi_class, BidiClass};
+use unicode_segmentation::UnicodeSegmentation;
+
+fn data(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "data", name]
+        .iter()
+        .collect();
+    fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "can't read {}: {}; see tests/data/README.md",
+            path.display(),
+            err
+        )
+    })
+}
+
+fn code_points(field: &str) -> String {
+    field
+        .split_whitespace()
+        .map(|hex| char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap())
+        .collect()
+}
+
+/// Characters rule X9 removes, which have no place in the visual ordering.
+fn is_removed(c: char) -> bool {
+    use BidiClass::*;
+    matches!(bidi_class(c), RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+struct BidiCase {
+    text: String,
+    direction: Option<Direction>,
+    /// Indices into `text`'s chars, left to right.
+    visual: Vec<usize>,
+}
+
+/// Reads a line of `BidiCharacterTest.txt`: code points; paragraph direction;
+/// resolved paragraph level; resolved levels; visual ordering.
+fn parse_bidi_case(line: &str) -> Option<BidiCase> {
+    let line = line.split('#').next()?.trim();
+    let fields: Vec<&str> = line.split(';').collect();
+    if fields.len() != 5 {
+        return None;
+    }
+    Some(BidiCase {
+        text: code_points(fields[0]),
+        direction: match fields[1] {
+            "0" => Some(Direction::Ltr),
+            "1" => Some(Direction::Rtl),
+            _ => None,
+        },
+        visual: fields[4]
+            .split_whitespace()
+            .map(|i| i.parse().unwrap())
+            .collect(),
+    })
+}
+
+#[test]
+fn test_parse_bidi_case() {
+    // a made-up line in the file's format, not taken from the Unicode data
+    let case = parse_bidi_case("0061 05D0 05D1;0;0;0 1 1;0 2 1").unwrap();
+    assert_eq!(case.text, "a\u{5d0}\u{5d1}");
+    assert_eq!(case.direction, Some(Direction::Ltr));
+    assert_eq!(case.visual, vec![0, 2, 1]);
+    assert!(parse_bidi_case("# comment").is_none());
+}
+
+#[test]
+fn test_bidi_character_test() {
+    let text = data("BidiCharacterTest.txt");
+    let (mut checked, mut failures) = (0, vec![]);
+    for line in text.lines() {
+        let Some(case) = parse_bidi_case(line) else {
+            continue;
+        };
+        let chars: Vec<char> = case.text.chars().collect();
+        // the file orders single characters; clusters are kept whole here
+        if case.text.graphemes(true).count() != chars.len() {
+            continue;
+        }
+        let expected: String = case.visual.iter().rev().map(|&i| chars[i]).collect();
+        let actual: String = reverse_visual_in(&case.text, case.direction)
+            .chars()
+            .filter(|&c| !is_removed(c))
+            .collect();
+        checked += 1;
+        if actual != expected {
+            failures.push(line.to_string());
+        }
+    }
+    assert!(checked > 0);
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases failed, e.g. {:?}",
+        failures.len(),
+        checked,
+        &failures[..failures.len().min(5)]
+    );
+}
+
+#[test]
+fn test_emoji_sequences_stay_intact() {
+    let text = data("emoji-test.txt");
+    let mut checked = 0;
+    for line in text.lines() {
+        let Some((code_points_field, status)) = line.split('#').next().unwrap().split_once(';')
+        else {
+            continue;
+        };
+        if status.trim() != "fully-qualified" {
+            continue;
+        }
+        let emoji = code_points(code_points_field);
+        let text = format!("a{}b\u{5d0}", emoji);
+        assert_eq!(reverse(&text), format!("\u{5d0}b{}a", emoji));
+        assert_eq!(
+            reverse_visual(&format!("a{}b", emoji)),
+            format!("b{}a", emoji)
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
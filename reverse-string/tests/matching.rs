@@ -0,0 +1,94 @@
+use reverse_string::*;
+
+const EXACT: MatchOptions = MatchOptions {
+    ignore_case: false,
+    ignore_punctuation: false,
+    normalization: None,
+};
+
+const LOOSE: MatchOptions = MatchOptions {
+    ignore_case: true,
+    ignore_punctuation: true,
+    normalization: Some(Normalization::Nfc),
+};
+
+#[test]
+fn test_exact_palindromes() {
+    assert_eq!(MatchOptions::default(), EXACT);
+    assert!(is_palindrome("racecar", EXACT));
+    assert!(is_palindrome("", EXACT));
+    assert!(!is_palindrome("Racecar", EXACT));
+    assert!(!is_palindrome("robot", EXACT));
+}
+
+#[test]
+fn test_ignore_case_and_punctuation() {
+    let sentence = "A man, a plan, a canal: Panama!";
+    assert!(!is_palindrome(sentence, EXACT));
+    assert!(is_palindrome(sentence, LOOSE));
+    let no_case = MatchOptions {
+        ignore_case: true,
+        ..EXACT
+    };
+    assert!(is_palindrome("Racecar", no_case));
+    assert!(!is_palindrome("Race car", no_case));
+}
+
+#[test]
+fn test_full_case_folding() {
+    let no_case = MatchOptions {
+        ignore_case: true,
+        ..EXACT
+    };
+    // ß folds to ss, which simple lowercasing wouldn't do
+    assert!(is_palindrome("ßS", no_case));
+    assert!(is_anagram("Straße", "STRASSE", no_case));
+}
+
+#[test]
+fn test_graphemes_not_chars() {
+    // reversed char by char, the accent would land on the wrong letter
+    assert!(is_palindrome("e\u{301}te\u{301}", EXACT));
+    assert!(is_palindrome("👍🏽a👍🏽", EXACT));
+    assert!(!is_palindrome("👍🏽👍🏿", EXACT));
+}
+
+#[test]
+fn test_normalization() {
+    let mixed = "\u{e9}te\u{301}";
+    assert!(!is_palindrome(mixed, EXACT));
+    assert!(is_palindrome(
+        mixed,
+        MatchOptions {
+            normalization: Some(Normalization::Nfc),
+            ..EXACT
+        }
+    ));
+    assert!(is_palindrome(
+        mixed,
+        MatchOptions {
+            normalization: Some(Normalization::Nfkd),
+            ..EXACT
+        }
+    ));
+    let compatibility = MatchOptions {
+        normalization: Some(Normalization::Nfkd),
+        ..EXACT
+    };
+    assert!(is_anagram("\u{fb01}x", "xfi", compatibility));
+    let canonical = MatchOptions {
+        normalization: Some(Normalization::Nfc),
+        ..EXACT
+    };
+    assert!(!is_anagram("\u{fb01}x", "xfi", canonical));
+}
+
+#[test]
+fn test_anagrams() {
+    assert!(is_anagram("listen", "silent", EXACT));
+    assert!(!is_anagram("listen", "Silent", EXACT));
+    assert!(is_anagram("Dormitory", "dirty room!", LOOSE));
+    assert!(!is_anagram("abc", "abcc", LOOSE));
+    assert!(is_anagram("cafe\u{301}", "\u{e9}fac", LOOSE));
+    assert!(!is_anagram("cafe\u{301}", "\u{e9}fac", EXACT));
+}
@@ -0,0 +1,61 @@
+use std::io::{self, BufReader};
+
+use reverse_string::*;
+
+#[test]
+fn test_reversed_graphemes() {
+    let graphemes: Vec<&str> = reversed_graphemes("uüu!").collect();
+    assert_eq!(graphemes, vec!["!", "u", "ü", "u"]);
+    assert_eq!(reversed_graphemes("").next(), None);
+}
+
+#[test]
+fn test_reversed_graphemes_borrow_input() {
+    let input = String::from("ab");
+    let first = reversed_graphemes(&input).next().unwrap();
+    assert_eq!(first.as_ptr(), input[1..].as_ptr());
+}
+
+#[test]
+fn test_reversed_graphemes_both_ends() {
+    let mut graphemes = reversed_graphemes("abc");
+    assert_eq!(graphemes.next_back(), Some("a"));
+    assert_eq!(graphemes.next(), Some("c"));
+    assert_eq!(graphemes.collect::<String>(), "b");
+}
+
+#[test]
+fn test_reverse_into() {
+    let mut output = String::from("> ");
+    reverse_into("子猫 👨‍👩‍👧", &mut output).unwrap();
+    assert_eq!(output, "> 👨‍👩‍👧 猫子");
+}
+
+#[test]
+fn test_reverse_lines() {
+    let input = "robot\r\nI'm hungry!\n\nracecar";
+    let mut output = vec![];
+    reverse_lines(input.as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "tobor\r\n!yrgnuh m'I\n\nracecar"
+    );
+}
+
+#[test]
+fn test_reverse_lines_small_buffer() {
+    let input = "uüu\nnoe\u{301}l\n".repeat(50);
+    let reader = BufReader::with_capacity(3, input.as_bytes());
+    let mut output = vec![];
+    reverse_lines(reader, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "uüu\nle\u{301}on\n".repeat(50)
+    );
+}
+
+#[test]
+fn test_reverse_lines_rejects_invalid_utf8() {
+    let err = reverse_lines(&b"ok\n\xff\n"[..], io::sink()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
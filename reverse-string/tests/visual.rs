@@ -0,0 +1,68 @@
+use reverse_string::*;
+
+#[test]
+fn test_left_to_right_matches_reverse() {
+    for text in ["robot", "I'm hungry!", "子猫", "ab\ncd", ""] {
+        assert_eq!(reverse_visual(text), reverse(text));
+    }
+}
+
+#[test]
+fn test_right_to_left_run() {
+    // displayed as "abc גבא", read back from the right
+    assert_eq!(reverse_visual("abc אבג"), "אבג cba");
+}
+
+#[test]
+fn test_right_to_left_paragraph() {
+    // displayed right-aligned as "abc גבא"
+    assert_eq!(reverse_visual("אבג abc"), "אבג cba");
+    // displayed as "םולש", which reads "שלום" from the right
+    assert_eq!(reverse_visual("שלום"), "שלום");
+}
+
+#[test]
+fn test_numbers_in_right_to_left_text() {
+    // displayed as "123 גבא"
+    assert_eq!(reverse_visual("אבג 123"), "אבג 321");
+}
+
+#[test]
+fn test_forced_direction() {
+    assert_eq!(
+        reverse_visual_in("abc אבג", Some(Direction::Rtl)),
+        "cba אבג"
+    );
+    assert_eq!(
+        reverse_visual_in("אבג abc", Some(Direction::Ltr)),
+        "cba אבג"
+    );
+    assert_eq!(reverse_visual_in("abc", Some(Direction::Rtl)), "cba");
+}
+
+#[test]
+fn test_paragraphs_keep_their_own_direction() {
+    assert_eq!(reverse_visual("abc\nאבג"), "אבג\ncba");
+    assert_eq!(reverse_visual("abc\u{2029}אבג d"), "אבג d\u{2029}cba");
+    assert_eq!(reverse_visual("a\r\nb"), "b\r\na");
+}
+
+#[test]
+fn test_emoji_sequences_stay_intact() {
+    let flag = "\u{1f1e9}\u{1f1ea}";
+    let skin_tone = "\u{1f44d}\u{1f3fd}";
+    let keycap = "1\u{fe0f}\u{20e3}";
+    let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+    let text = format!("{} {} {} {}", flag, skin_tone, keycap, family);
+    let expected = format!("{} {} {} {}", family, keycap, skin_tone, flag);
+    assert_eq!(reverse_visual(&text), expected);
+    // displayed as "דג 1️⃣ בא"
+    assert_eq!(
+        reverse_visual(&format!("אב {} גד", keycap)),
+        format!("אב {} גד", keycap)
+    );
+    assert_eq!(
+        reverse_visual(&format!("x{}y אב", flag)),
+        format!("אב y{}x", flag)
+    );
+}
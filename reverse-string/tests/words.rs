@@ -0,0 +1,37 @@
+use reverse_string::*;
+
+#[test]
+fn test_reverse_words() {
+    assert_eq!(reverse_words("Hello, world!"), "world, Hello!");
+    assert_eq!(reverse_words("one two three"), "three two one");
+}
+
+#[test]
+fn test_reverse_words_keeps_whitespace_and_punctuation() {
+    assert_eq!(
+        reverse_words("  alpha,\tbeta  -- gamma. "),
+        "  gamma,\tbeta  -- alpha. "
+    );
+    assert_eq!(reverse_words("...!"), "...!");
+    assert_eq!(reverse_words(""), "");
+}
+
+#[test]
+fn test_reverse_words_uses_word_boundaries() {
+    // apostrophes and decimal points inside words don't split them
+    assert_eq!(reverse_words("I'm paying 3.50 now"), "now 3.50 paying I'm");
+    // while every ideograph is a word of its own
+    assert_eq!(reverse_words("子猫 cat"), "cat猫 子");
+}
+
+#[test]
+fn test_reverse_each_word() {
+    assert_eq!(reverse_each_word("Hello, world!"), "olleH, dlrow!");
+    assert_eq!(reverse_each_word("I'm hungry!"), "m'I yrgnuh!");
+    assert_eq!(reverse_each_word("  a  bc\n"), "  a  cb\n");
+}
+
+#[test]
+fn test_reverse_each_word_keeps_graphemes() {
+    assert_eq!(reverse_each_word("noe\u{301}l day"), "le\u{301}on yad");
+}
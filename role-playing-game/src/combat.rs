@@ -0,0 +1,336 @@
+use std::ops::RangeInclusive;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{CastResult, Effect, InventoryError, Player, StatusEvent, Target};
+
+/// Damage rolled by a plain attack, before the attacker's level and weapon bonus.
+pub const ATTACK_DAMAGE: RangeInclusive<u32> = 5..=15;
+/// Every this many levels add one point to attack damage.
+pub const LEVELS_PER_DAMAGE: u32 = 5;
+pub const HIT_CHANCE: f64 = 0.9;
+pub const DEFAULT_MAX_TURNS: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Attacker,
+    Defender,
+}
+
+impl Side {
+    pub fn opponent(self) -> Side {
+        match self {
+            Side::Attacker => Side::Defender,
+            Side::Defender => Side::Attacker,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// What a player does with their turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Attack,
+    /// Casts [`Player::cast_spell`] with this mana cost at the opponent.
+    CastSpell(u32),
+    /// Casts a spell from the player's spellbook.
+    Cast(String),
+    /// Halves all damage taken until the player's next turn.
+    Defend,
+    /// Uses an item from the player's inventory on themselves.
+    UseItem(String),
+}
+
+/// One entry of the battle log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Turn {
+        number: u32,
+        side: Side,
+    },
+    Hit {
+        by: Side,
+        damage: u32,
+    },
+    Miss {
+        by: Side,
+    },
+    /// `damage` is what reached the opponent; a caster without a mana pool
+    /// pays `mana_cost` in health instead.
+    Spell {
+        by: Side,
+        mana_cost: u32,
+        damage: u32,
+    },
+    /// Followed by an [`Event::Effect`] for each effect that landed.
+    Cast {
+        by: Side,
+        spell: String,
+        result: CastResult,
+    },
+    /// `effect` holds what `side` actually took, after defending.
+    Effect {
+        side: Side,
+        effect: Effect,
+    },
+    Defend {
+        by: Side,
+    },
+    UseItem {
+        by: Side,
+        item: String,
+        result: Result<(), InventoryError>,
+    },
+    /// A status effect on `side` took its turn.
+    Status {
+        side: Side,
+        event: StatusEvent,
+    },
+    /// `side` fell, but a phoenix feather brought them back.
+    Revived {
+        side: Side,
+    },
+    Defeated {
+        side: Side,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleOutcome {
+    Victory(Side),
+    /// Both players fell, or neither did within the turn limit.
+    Draw,
+}
+
+/// A one-on-one fight. The attacker takes the first turn, then the two sides
+/// alternate until one of them is down.
+///
+/// Status effects run at the start of their owner's turn, before the action;
+/// a stunned player's action is skipped.
+///
+/// All randomness comes from `R`, so a battle built with the same seed and
+/// fed the same actions always plays out the same way.
+#[derive(Debug, Clone)]
+pub struct Battle<R = ChaCha8Rng> {
+    players: [Player; 2],
+    defending: [bool; 2],
+    turn: u32,
+    max_turns: u32,
+    rng: R,
+    log: Vec<Event>,
+    outcome: Option<BattleOutcome>,
+}
+
+impl Battle {
+    pub fn new(attacker: Player, defender: Player, seed: u64) -> Self {
+        Battle::with_rng(attacker, defender, ChaCha8Rng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> Battle<R> {
+    pub fn with_rng(attacker: Player, defender: Player, rng: R) -> Self {
+        let mut battle = Battle {
+            players: [attacker, defender],
+            defending: [false; 2],
+            turn: 0,
+            max_turns: DEFAULT_MAX_TURNS,
+            rng,
+            log: vec![],
+            outcome: None,
+        };
+        battle.check_defeated();
+        battle
+    }
+
+    /// Ends the battle in a draw after this many turns.
+    pub fn with_max_turns(mut self, max_turns: u32) -> Self {
+        self.max_turns = max_turns;
+        self
+    }
+
+    pub fn attacker(&self) -> &Player {
+        self.player(Side::Attacker)
+    }
+
+    pub fn defender(&self) -> &Player {
+        self.player(Side::Defender)
+    }
+
+    pub fn player(&self, side: Side) -> &Player {
+        &self.players[side.index()]
+    }
+
+    /// Whose turn comes next.
+    pub fn side_to_act(&self) -> Side {
+        match self.turn % 2 {
+            0 => Side::Attacker,
+            _ => Side::Defender,
+        }
+    }
+
+    /// The number of turns taken so far.
+    pub fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    pub fn log(&self) -> &[Event] {
+        &self.log
+    }
+
+    pub fn outcome(&self) -> Option<BattleOutcome> {
+        self.outcome
+    }
+
+    pub fn into_players(self) -> (Player, Player) {
+        let [attacker, defender] = self.players;
+        (attacker, defender)
+    }
+
+    /// Plays `action` for the side whose turn it is.
+    ///
+    /// Does nothing once the battle is over; returns the outcome as soon as there is one.
+    pub fn take_turn(&mut self, action: Action) -> Option<BattleOutcome> {
+        if self.outcome.is_some() {
+            return self.outcome;
+        }
+        let side = self.side_to_act();
+        self.turn += 1;
+        self.log.push(Event::Turn {
+            number: self.turn,
+            side,
+        });
+        self.defending[side.index()] = false;
+        self.players[side.index()].spellbook.tick();
+        let mut skipped = false;
+        for event in self.players[side.index()].tick_statuses() {
+            skipped |= event == StatusEvent::Stunned;
+            self.log.push(Event::Status { side, event });
+        }
+        // poison may have finished them off
+        skipped |= self.player(side).health == 0;
+        match action {
+            _ if skipped => {}
+            Action::Attack => match self.rng.gen_bool(HIT_CHANCE) {
+                true => {
+                    let player = self.player(side);
+                    let bonus = player.level / LEVELS_PER_DAMAGE + player.inventory.bonus().attack;
+                    let rolled = self.rng.gen_range(ATTACK_DAMAGE) + bonus;
+                    let damage = self.hit(side.opponent(), rolled);
+                    self.log.push(Event::Hit { by: side, damage });
+                }
+                false => self.log.push(Event::Miss { by: side }),
+            },
+            Action::CastSpell(mana_cost) => {
+                let rolled = self.players[side.index()].cast_spell(mana_cost);
+                let damage = self.hit(side.opponent(), rolled);
+                self.log.push(Event::Spell {
+                    by: side,
+                    mana_cost,
+                    damage,
+                });
+            }
+            Action::Cast(spell) => {
+                let result = self.players[side.index()].cast(&spell);
+                let effects = match &result {
+                    CastResult::Success { target, effects } => {
+                        let target = match target {
+                            Target::Opponent => side.opponent(),
+                            Target::Caster => side,
+                        };
+                        effects.iter().map(|&effect| (target, effect)).collect()
+                    }
+                    _ => vec![],
+                };
+                self.log.push(Event::Cast {
+                    by: side,
+                    spell,
+                    result,
+                });
+                for (target, effect) in effects {
+                    let effect = match effect {
+                        Effect::Damage(amount) => Effect::Damage(self.hit(target, amount)),
+                        Effect::Heal(_) | Effect::Status(_) => {
+                            self.players[target.index()].apply(effect);
+                            effect
+                        }
+                    };
+                    self.log.push(Event::Effect {
+                        side: target,
+                        effect,
+                    });
+                }
+            }
+            Action::UseItem(item) => {
+                let result = self.players[side.index()].use_item(&item);
+                self.log.push(Event::UseItem {
+                    by: side,
+                    item,
+                    result,
+                });
+            }
+            Action::Defend => {
+                self.defending[side.index()] = true;
+                self.log.push(Event::Defend { by: side });
+            }
+        }
+        self.check_defeated();
+        if self.outcome.is_none() && self.turn >= self.max_turns {
+            self.outcome = Some(BattleOutcome::Draw);
+        }
+        self.outcome
+    }
+
+    /// Plays turns until the battle ends, asking `strategy` for each action.
+    ///
+    /// `strategy` is given the side to act, that side's player and its opponent.
+    pub fn fight<F>(&mut self, mut strategy: F) -> BattleOutcome
+    where
+        F: FnMut(Side, &Player, &Player) -> Action,
+    {
+        loop {
+            let side = self.side_to_act();
+            let action = strategy(side, self.player(side), self.player(side.opponent()));
+            if let Some(outcome) = self.take_turn(action) {
+                return outcome;
+            }
+        }
+    }
+
+    /// Deals `damage` to `side`, halved if they are defending and less their
+    /// armor's defense, and returns the health they lost.
+    fn hit(&mut self, side: Side, damage: u32) -> u32 {
+        let damage = match self.defending[side.index()] {
+            true => damage / 2,
+            false => damage,
+        };
+        let player = &mut self.players[side.index()];
+        let defense = player.inventory.bonus().defense;
+        player.take_damage(damage.saturating_sub(defense))
+    }
+
+    fn check_defeated(&mut self) {
+        let mut down = vec![];
+        for side in [Side::Attacker, Side::Defender] {
+            let player = &mut self.players[side.index()];
+            if player.health > 0 {
+                continue;
+            }
+            match player.try_phoenix_feather() {
+                true => self.log.push(Event::Revived { side }),
+                false => {
+                    self.log.push(Event::Defeated { side });
+                    down.push(side);
+                }
+            }
+        }
+        self.outcome = match down[..] {
+            [] => None,
+            [side] => Some(BattleOutcome::Victory(side.opponent())),
+            _ => Some(BattleOutcome::Draw),
+        };
+    }
+}
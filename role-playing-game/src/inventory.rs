@@ -0,0 +1,388 @@
+use std::fmt;
+
+use crate::{Player, StatusEffect};
+
+/// How much weight a new inventory can carry.
+pub const DEFAULT_CAPACITY: u32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Slot {
+    Weapon,
+    Armor,
+    Trinket,
+}
+
+impl Slot {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Stat bonuses from equipment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Added to attack damage.
+    pub attack: u32,
+    /// Taken off every hit in battle.
+    pub defense: u32,
+    pub max_health: u32,
+    pub max_mana: u32,
+}
+
+impl Stats {
+    fn add(self, other: Stats) -> Stats {
+        Stats {
+            attack: self.attack.saturating_add(other.attack),
+            defense: self.defense.saturating_add(other.defense),
+            max_health: self.max_health.saturating_add(other.max_health),
+            max_mana: self.max_mana.saturating_add(other.max_mana),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemKind {
+    /// Restores health and mana, and may apply a status effect to the drinker.
+    Potion {
+        health: u32,
+        mana: u32,
+        status: Option<StatusEffect>,
+    },
+    /// Revives a dead player.
+    PhoenixFeather,
+    Equipment {
+        slot: Slot,
+        bonus: Stats,
+        min_level: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub name: String,
+    pub weight: u32,
+    pub kind: ItemKind,
+}
+
+impl Item {
+    pub fn potion(name: &str, health: u32, mana: u32) -> Self {
+        Item {
+            name: name.to_string(),
+            weight: 1,
+            kind: ItemKind::Potion {
+                health,
+                mana,
+                status: None,
+            },
+        }
+    }
+
+    pub fn phoenix_feather() -> Self {
+        Item {
+            name: "phoenix feather".to_string(),
+            weight: 1,
+            kind: ItemKind::PhoenixFeather,
+        }
+    }
+
+    pub fn equipment(name: &str, weight: u32, slot: Slot, bonus: Stats) -> Self {
+        Item {
+            name: name.to_string(),
+            weight,
+            kind: ItemKind::Equipment {
+                slot,
+                bonus,
+                min_level: 0,
+            },
+        }
+    }
+
+    /// Sets the level needed to equip this, if it's equipment.
+    pub fn with_min_level(mut self, level: u32) -> Self {
+        if let ItemKind::Equipment { min_level, .. } = &mut self.kind {
+            *min_level = level;
+        }
+        self
+    }
+
+    /// Sets the status effect this applies, if it's a potion.
+    pub fn with_status(mut self, effect: StatusEffect) -> Self {
+        if let ItemKind::Potion { status, .. } = &mut self.kind {
+            *status = Some(effect);
+        }
+        self
+    }
+
+    /// Consumables stack; each piece of equipment takes its own place.
+    pub fn is_stackable(&self) -> bool {
+        !matches!(self.kind, ItemKind::Equipment { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InventoryError {
+    /// Adding the items would take the weight to `weight`, over `capacity`.
+    Overweight {
+        weight: u32,
+        capacity: u32,
+    },
+    NotFound(String),
+    /// The stack of this item can't hold any more.
+    StackFull(String),
+    NotEquipment(String),
+    LevelTooLow {
+        required: u32,
+    },
+    /// The item can't be used on the player as they are, e.g. a potion on the dead.
+    CannotUse(String),
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InventoryError::Overweight { weight, capacity } => {
+                write!(
+                    f,
+                    "carrying {} would exceed the capacity of {}",
+                    weight, capacity
+                )
+            }
+            InventoryError::NotFound(name) => write!(f, "no `{}` in the inventory", name),
+            InventoryError::StackFull(name) => write!(f, "can't carry any more `{}`", name),
+            InventoryError::NotEquipment(name) => write!(f, "`{}` can't be equipped", name),
+            InventoryError::LevelTooLow { required } => {
+                write!(f, "requires level {}", required)
+            }
+            InventoryError::CannotUse(name) => write!(f, "`{}` can't be used now", name),
+        }
+    }
+}
+
+impl std::error::Error for InventoryError {}
+
+/// Carried items and equipped gear. Equipped gear counts towards the weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    stacks: Vec<(Item, u32)>,
+    equipped: [Option<Item>; 3],
+    capacity: u32,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Inventory::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl Inventory {
+    pub fn new(capacity: u32) -> Self {
+        Inventory {
+            stacks: vec![],
+            equipped: Default::default(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// The total weight, or `u32::MAX` if it's more than that.
+    pub fn weight(&self) -> u32 {
+        let carried: u64 = self
+            .stacks
+            .iter()
+            .map(|(item, count)| u64::from(item.weight) * u64::from(*count))
+            .sum();
+        let equipped: u64 = self
+            .equipped
+            .iter()
+            .flatten()
+            .map(|item| u64::from(item.weight))
+            .sum();
+        u32::try_from(carried + equipped).unwrap_or(u32::MAX)
+    }
+
+    /// Adds `count` of `item`; adding none does nothing.
+    pub fn add(&mut self, item: Item, count: u32) -> Result<(), InventoryError> {
+        if count == 0 {
+            return Ok(());
+        }
+        let weight = item
+            .weight
+            .checked_mul(count)
+            .and_then(|added| added.checked_add(self.weight()))
+            .unwrap_or(u32::MAX);
+        if weight > self.capacity {
+            return Err(InventoryError::Overweight {
+                weight,
+                capacity: self.capacity,
+            });
+        }
+        match item.is_stackable() {
+            true => match self.stacks.iter_mut().find(|(stacked, _)| *stacked == item) {
+                Some((_, stacked)) => {
+                    *stacked = stacked
+                        .checked_add(count)
+                        .ok_or_else(|| InventoryError::StackFull(item.name.clone()))?
+                }
+                None => self.stacks.push((item, count)),
+            },
+            false => (0..count).for_each(|_| self.stacks.push((item.clone(), 1))),
+        }
+        Ok(())
+    }
+
+    /// Takes one `name` out of the inventory.
+    pub fn remove(&mut self, name: &str) -> Result<Item, InventoryError> {
+        let i = self
+            .stacks
+            .iter()
+            .position(|(item, _)| item.name == name)
+            .ok_or_else(|| InventoryError::NotFound(name.to_string()))?;
+        self.stacks[i].1 -= 1;
+        match self.stacks[i].1 {
+            0 => Ok(self.stacks.remove(i).0),
+            _ => Ok(self.stacks[i].0.clone()),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Item> {
+        self.items().find(|item| item.name == name)
+    }
+
+    /// How many `name` are carried, not counting equipped gear, or
+    /// `u32::MAX` if it's more than that.
+    pub fn count(&self, name: &str) -> u32 {
+        let count: u64 = self
+            .stacks
+            .iter()
+            .filter(|(item, _)| item.name == name)
+            .map(|&(_, count)| u64::from(count))
+            .sum();
+        u32::try_from(count).unwrap_or(u32::MAX)
+    }
+
+    /// Every carried item once, whatever the size of its stack.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.stacks.iter().map(|(item, _)| item)
+    }
+
+    pub fn equipped(&self, slot: Slot) -> Option<&Item> {
+        self.equipped[slot.index()].as_ref()
+    }
+
+    /// The bonuses of all equipped gear together.
+    pub fn bonus(&self) -> Stats {
+        self.equipped
+            .iter()
+            .flatten()
+            .fold(Stats::default(), |total, item| match item.kind {
+                ItemKind::Equipment { bonus, .. } => total.add(bonus),
+                _ => total,
+            })
+    }
+
+    /// Puts the gear in `slot` back in the bag. Use [`Player::unequip`] on a
+    /// player's inventory so their health and mana follow the lost bonus.
+    pub(crate) fn unequip(&mut self, slot: Slot) -> Option<&Item> {
+        let item = self.equipped[slot.index()].take()?;
+        self.stacks.push((item, 1));
+        self.stacks.last().map(|(item, _)| item)
+    }
+}
+
+impl Player {
+    pub fn max_health_with_gear(&self) -> u32 {
+        self.max_health
+            .saturating_add(self.inventory.bonus().max_health)
+    }
+
+    pub fn max_mana_with_gear(&self) -> u32 {
+        self.max_mana
+            .saturating_add(self.inventory.bonus().max_mana)
+    }
+
+    /// Equips `name` from the inventory, putting whatever was in its slot back in the bag.
+    pub fn equip(&mut self, name: &str) -> Result<(), InventoryError> {
+        let (slot, min_level) = match self.inventory.get(name).map(|item| &item.kind) {
+            Some(&ItemKind::Equipment {
+                slot, min_level, ..
+            }) => (slot, min_level),
+            Some(_) => return Err(InventoryError::NotEquipment(name.to_string())),
+            None => return Err(InventoryError::NotFound(name.to_string())),
+        };
+        if self.level < min_level {
+            return Err(InventoryError::LevelTooLow {
+                required: min_level,
+            });
+        }
+        let item = self.inventory.remove(name)?;
+        self.inventory.unequip(slot);
+        self.inventory.equipped[slot.index()] = Some(item);
+        self.clamp_to_gear();
+        Ok(())
+    }
+
+    /// Puts the gear in `slot` back in the bag, bringing health and mana down
+    /// to the new maximums.
+    pub fn unequip(&mut self, slot: Slot) -> Option<&Item> {
+        self.inventory.unequip(slot)?;
+        self.clamp_to_gear();
+        self.inventory.stacks.last().map(|(item, _)| item)
+    }
+
+    /// Uses up one `name`. A potion needs a living drinker, a phoenix feather a dead one.
+    pub fn use_item(&mut self, name: &str) -> Result<(), InventoryError> {
+        let item = self
+            .inventory
+            .get(name)
+            .ok_or_else(|| InventoryError::NotFound(name.to_string()))?;
+        match (&item.kind, self.health) {
+            (ItemKind::Potion { .. }, 1..) | (ItemKind::PhoenixFeather, 0) => {}
+            _ => return Err(InventoryError::CannotUse(name.to_string())),
+        }
+        let item = self.inventory.remove(name)?;
+        match item.kind {
+            ItemKind::Potion {
+                health,
+                mana,
+                status,
+            } => {
+                self.health = self
+                    .health
+                    .saturating_add(health)
+                    .min(self.max_health_with_gear());
+                let max_mana = self.max_mana_with_gear();
+                self.mana = self
+                    .mana
+                    .map(|current| current.saturating_add(mana).min(max_mana));
+                if let Some(status) = status {
+                    self.statuses.apply(status);
+                }
+            }
+            ItemKind::PhoenixFeather => *self = self.revive().expect("the player is dead"),
+            ItemKind::Equipment { .. } => unreachable!("checked above"),
+        }
+        Ok(())
+    }
+
+    fn clamp_to_gear(&mut self) {
+        self.health = self.health.min(self.max_health_with_gear());
+        let max_mana = self.max_mana_with_gear();
+        self.mana = self.mana.map(|current| current.min(max_mana));
+    }
+
+    /// Uses a phoenix feather if the player is dead and carries one.
+    pub fn try_phoenix_feather(&mut self) -> bool {
+        let feather = self
+            .inventory
+            .items()
+            .find(|item| item.kind == ItemKind::PhoenixFeather)
+            .map(|item| item.name.clone());
+        match feather {
+            Some(name) => self.use_item(&name).is_ok(),
+            None => false,
+        }
+    }
+}
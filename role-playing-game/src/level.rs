@@ -0,0 +1,221 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Player;
+
+pub const HEALTH_PER_LEVEL: u32 = 10;
+pub const MANA_PER_LEVEL: u32 = 5;
+/// Players get a mana pool when they reach this level.
+pub const MANA_UNLOCK_LEVEL: u32 = 10;
+
+/// No curve goes past this level.
+pub const MAX_LEVEL: u32 = 100;
+
+/// How much experience, in total, each level takes.
+///
+/// Every level costs more experience than the one before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XpCurve(Curve);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Curve {
+    Linear { per_level: u64 },
+    Exponential { base: u64, factor: f64 },
+    Table(Vec<u64>),
+}
+
+impl XpCurve {
+    /// Every level costs `per_level`.
+    pub fn linear(per_level: u64) -> Result<XpCurve, CurveError> {
+        match per_level {
+            0 => Err(CurveError::Flat),
+            _ => Ok(XpCurve(Curve::Linear { per_level })),
+        }
+    }
+
+    /// Level 2 costs `base`, and each level after that `factor` times the one before.
+    pub fn exponential(base: u64, factor: f64) -> Result<XpCurve, CurveError> {
+        if base == 0 {
+            return Err(CurveError::Flat);
+        }
+        match factor.is_finite() && factor >= 1.0 {
+            true => Ok(XpCurve(Curve::Exponential { base, factor })),
+            false => Err(CurveError::InvalidFactor(factor)),
+        }
+    }
+
+    /// The total experience needed for levels 2, 3, ...; there are no levels past the end.
+    pub fn table(totals: Vec<u64>) -> Result<XpCurve, CurveError> {
+        if totals.is_empty() {
+            return Err(CurveError::Empty);
+        }
+        match totals.windows(2).position(|pair| pair[1] <= pair[0]) {
+            Some(i) => Err(CurveError::NotIncreasing {
+                level: i as u32 + 3,
+            }),
+            None => Ok(XpCurve(Curve::Table(totals))),
+        }
+    }
+
+    /// Reads a table of totals, one per line. Blank lines and `#` comments are skipped.
+    pub fn parse_table(text: &str) -> Result<XpCurve, CurveError> {
+        let mut totals = vec![];
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            totals.push(line.parse().map_err(|_| CurveError::InvalidNumber {
+                line: line_number,
+                text: line.to_string(),
+            })?);
+        }
+        XpCurve::table(totals)
+    }
+
+    pub fn load_table(path: impl AsRef<Path>) -> Result<XpCurve, CurveError> {
+        XpCurve::parse_table(&fs::read_to_string(path).map_err(CurveError::Io)?)
+    }
+
+    /// Each level from 2 up with the total experience it takes, as far as the curve goes.
+    fn thresholds(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        let mut total: u64 = 0;
+        let mut cost = match self.0 {
+            Curve::Exponential { base, .. } => base as f64,
+            _ => 0.0,
+        };
+        (2..=MAX_LEVEL).map_while(move |level| {
+            total = match &self.0 {
+                Curve::Linear { per_level } => total.checked_add(*per_level)?,
+                Curve::Exponential { factor, .. } => {
+                    let rounded = cost.round();
+                    cost *= factor;
+                    match rounded < u64::MAX as f64 {
+                        true => total.checked_add(rounded as u64)?,
+                        false => return None,
+                    }
+                }
+                Curve::Table(totals) => *totals.get(level as usize - 2)?,
+            };
+            Some((level, total))
+        })
+    }
+
+    /// The total experience needed to reach `level`, or `None` if it can't be reached.
+    pub fn xp_for_level(&self, level: u32) -> Option<u64> {
+        match level {
+            0 | 1 => Some(0),
+            _ => self
+                .thresholds()
+                .find(|&(reached, _)| reached == level)
+                .map(|(_, total)| total),
+        }
+    }
+
+    /// The level a player with `xp` in total has reached.
+    pub fn level_for_xp(&self, xp: u64) -> u32 {
+        self.thresholds()
+            .take_while(|&(_, total)| total <= xp)
+            .last()
+            .map_or(1, |(level, _)| level)
+    }
+}
+
+#[derive(Debug)]
+pub enum CurveError {
+    Io(io::Error),
+    InvalidNumber {
+        line: usize,
+        text: String,
+    },
+    /// A linear or exponential curve would make levels cost nothing.
+    Flat,
+    /// Exponential factors must be at least 1.
+    InvalidFactor(f64),
+    /// The total for `level` is not larger than the one before.
+    NotIncreasing {
+        level: u32,
+    },
+    Empty,
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CurveError::Io(err) => write!(f, "can't read xp table: {}", err),
+            CurveError::InvalidNumber { line, text } => {
+                write!(f, "`{}` on line {} is not an xp total", text, line)
+            }
+            CurveError::Flat => write!(f, "levels must cost some xp"),
+            CurveError::InvalidFactor(factor) => {
+                write!(
+                    f,
+                    "xp factor {} is not a finite number of at least 1",
+                    factor
+                )
+            }
+            CurveError::NotIncreasing { level } => {
+                write!(
+                    f,
+                    "xp total for level {} is not larger than the one before",
+                    level
+                )
+            }
+            CurveError::Empty => write!(f, "xp table has no levels"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
+/// What changed when a player reached `level`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+    pub level: u32,
+    pub max_health: u32,
+    pub max_mana: u32,
+    /// The player got their mana pool with this level.
+    pub mana_unlocked: bool,
+}
+
+impl Player {
+    /// Adds experience and levels up as far as `curve` allows.
+    ///
+    /// Each level raises max health by [`HEALTH_PER_LEVEL`] and max mana by
+    /// [`MANA_PER_LEVEL`], and current health and mana along with them;
+    /// a dead player stays dead.
+    pub fn gain_xp(&mut self, xp: u64, curve: &XpCurve) -> Vec<LevelUp> {
+        self.xp = self.xp.saturating_add(xp);
+        let reached: Vec<u32> = curve
+            .thresholds()
+            .skip_while(|&(level, _)| level <= self.level)
+            .take_while(|&(_, total)| total <= self.xp)
+            .map(|(level, _)| level)
+            .collect();
+        let mut level_ups = vec![];
+        for level in reached {
+            self.level = level;
+            self.max_health = self.max_health.saturating_add(HEALTH_PER_LEVEL);
+            if self.health > 0 {
+                self.health = self.health.saturating_add(HEALTH_PER_LEVEL);
+            }
+            self.max_mana = self.max_mana.saturating_add(MANA_PER_LEVEL);
+            let mana_unlocked = self.mana.is_none() && self.level >= MANA_UNLOCK_LEVEL;
+            self.mana = match self.mana {
+                Some(mana) => Some(mana.saturating_add(MANA_PER_LEVEL)),
+                None if mana_unlocked => Some(self.max_mana),
+                None => None,
+            };
+            level_ups.push(LevelUp {
+                level: self.level,
+                max_health: self.max_health,
+                max_mana: self.max_mana,
+                mana_unlocked,
+            });
+        }
+        level_ups
+    }
+}
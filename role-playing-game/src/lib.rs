@@ -2,54 +2,103 @@
 // to enable stricter warnings.
 #![allow(unused)]
 
+pub mod combat;
+pub mod inventory;
+pub mod level;
+pub mod spell;
+pub mod status;
+
+pub use combat::{Action, Battle, BattleOutcome, Event, Side};
+pub use inventory::{Inventory, InventoryError, Item, ItemKind, Slot, Stats};
+use level::MANA_UNLOCK_LEVEL;
+pub use level::{CurveError, LevelUp, XpCurve};
+pub use spell::{CastResult, Effect, Formula, Spell, SpellEffect, Spellbook, Target};
+pub use status::{StatusEffect, StatusEvent, StatusKind, Statuses};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Player {
     pub health: u32,
     pub mana: Option<u32>,
     pub level: u32,
+    pub xp: u64,
+    pub max_health: u32,
+    /// The size of the mana pool, once the player has one.
+    pub max_mana: u32,
+    pub spellbook: Spellbook,
+    pub statuses: Statuses,
+    pub inventory: Inventory,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player {
+            health: 100,
+            mana: None,
+            level: 1,
+            xp: 0,
+            max_health: 100,
+            max_mana: 100,
+            spellbook: Spellbook::new(),
+            statuses: Statuses::default(),
+            inventory: Inventory::default(),
+        }
+    }
 }
 
 impl Player {
+    /// Brings a dead player back with full health, and full mana if they have a pool.
+    ///
+    /// The revived player keeps their inventory; see [`Player::use_item`] to
+    /// revive with a phoenix feather.
     pub fn revive(&self) -> Option<Player> {
         match self.health {
-            0 => match self.level >= 10 {
-                true => {
-                    return Some(Player {
-                        health: 100,
-                        mana: Some(100),
-                        level: self.level,
-                    });
-                }
-                _ => {
-                    return Some(Player {
-                        health: 100,
-                        mana: None,
-                        level: self.level,
-                    });
-                }
-            },
+            0 => Some(Player {
+                health: self.max_health_with_gear(),
+                mana: match self.level >= MANA_UNLOCK_LEVEL {
+                    true => Some(self.max_mana_with_gear()),
+                    false => None,
+                },
+                statuses: Statuses::default(),
+                ..self.clone()
+            }),
             _ => None,
         }
     }
 
+    /// Casts [`Spell::arcane_bolt`] and returns the damage it deals.
     pub fn cast_spell(&mut self, mana_cost: u32) -> u32 {
-        match self.mana {
-            None => {
-                if mana_cost > self.health {
-                    self.health = 0;  // DEAAAAD
-                } else {
-                    self.health = self.health - mana_cost;
-                }
-                return 0;
+        Spell::arcane_bolt(mana_cost).cast(self).damage()
+    }
+
+    /// Casts `name` from the spellbook, starting its cooldown if it succeeds.
+    pub fn cast(&mut self, name: &str) -> CastResult {
+        let spell = match self.spellbook.get(name) {
+            Some(spell) => spell.clone(),
+            None => return CastResult::UnknownSpell,
+        };
+        match self.spellbook.cooldown(name) {
+            0 => {}
+            turns_left => return CastResult::OnCooldown { turns_left },
+        }
+        let result = spell.cast(self);
+        if let CastResult::Success { .. } = result {
+            self.spellbook.start_cooldown(name);
+        }
+        result
+    }
+
+    pub fn apply(&mut self, effect: Effect) {
+        match effect {
+            Effect::Damage(amount) => {
+                self.take_damage(amount);
             }
-            Some(mana) => {
-                if mana > mana_cost {
-                    self.mana = Some(mana - mana_cost);
-                    return mana_cost * 2;
-                } else {
-                    return 0;
-                }
+            Effect::Heal(amount) => {
+                self.health = self
+                    .health
+                    .saturating_add(amount)
+                    .min(self.max_health_with_gear())
             }
+            Effect::Status(status) => self.statuses.apply(status),
         }
-        return 2 * mana_cost;
     }
 }
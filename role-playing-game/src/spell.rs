@@ -0,0 +1,253 @@
+use crate::{Player, StatusEffect, StatusKind};
+
+/// How much a spell effect does: `base`, plus `per_level` for every level of the caster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Formula {
+    pub base: u32,
+    pub per_level: u32,
+}
+
+impl Formula {
+    pub fn fixed(base: u32) -> Self {
+        Formula { base, per_level: 0 }
+    }
+
+    pub fn amount(&self, level: u32) -> u32 {
+        self.base
+            .saturating_add(self.per_level.saturating_mul(level))
+    }
+}
+
+/// Who a spell's effects land on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Opponent,
+    Caster,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellEffect {
+    Damage(Formula),
+    Heal(Formula),
+    Status(StatusEffect),
+}
+
+/// A resolved [`SpellEffect`], with the caster's level applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    Damage(u32),
+    Heal(u32),
+    Status(StatusEffect),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spell {
+    pub name: String,
+    pub cost: u32,
+    pub effects: Vec<SpellEffect>,
+    pub target: Target,
+    /// How many of the caster's turns pass before it can be cast again;
+    /// 0 and 1 both allow casting it every turn.
+    pub cooldown: u32,
+    pub min_level: u32,
+}
+
+impl Spell {
+    pub fn new(name: &str, cost: u32, effect: SpellEffect) -> Self {
+        Spell {
+            name: name.to_string(),
+            cost,
+            effects: vec![effect],
+            target: match effect {
+                SpellEffect::Damage(_) => Target::Opponent,
+                SpellEffect::Heal(_) => Target::Caster,
+                SpellEffect::Status(status) => match status.kind {
+                    StatusKind::Poison | StatusKind::Stun => Target::Opponent,
+                    StatusKind::Regen | StatusKind::Shield => Target::Caster,
+                },
+            },
+            cooldown: 0,
+            min_level: 0,
+        }
+    }
+
+    /// The spell behind [`Player::cast_spell`]: deals twice its cost.
+    pub fn arcane_bolt(cost: u32) -> Self {
+        Spell::new(
+            "arcane bolt",
+            cost,
+            SpellEffect::Damage(Formula::fixed(cost.saturating_mul(2))),
+        )
+    }
+
+    pub fn with_effect(mut self, effect: SpellEffect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+
+    pub fn with_target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: u32) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn with_min_level(mut self, min_level: u32) -> Self {
+        self.min_level = min_level;
+        self
+    }
+
+    /// Checks the caster's level, pays the cost and resolves the effects.
+    ///
+    /// A caster without a mana pool pays the cost in health instead, which a
+    /// shield can absorb, and the spell fizzles. Cooldowns are up to the [`Spellbook`].
+    pub fn cast(&self, caster: &mut Player) -> CastResult {
+        if caster.level < self.min_level {
+            return CastResult::LevelTooLow {
+                required: self.min_level,
+            };
+        }
+        match caster.mana {
+            None => {
+                let health_lost = caster.take_damage(self.cost);
+                CastResult::PaidInHealth { health_lost }
+            }
+            Some(mana) if mana < self.cost => CastResult::InsufficientMana {
+                cost: self.cost,
+                mana,
+            },
+            Some(mana) => {
+                caster.mana = Some(mana - self.cost);
+                let effects = self
+                    .effects
+                    .iter()
+                    .map(|effect| match effect {
+                        SpellEffect::Damage(formula) => {
+                            Effect::Damage(formula.amount(caster.level))
+                        }
+                        SpellEffect::Heal(formula) => Effect::Heal(formula.amount(caster.level)),
+                        SpellEffect::Status(status) => Effect::Status(*status),
+                    })
+                    .collect();
+                CastResult::Success {
+                    target: self.target,
+                    effects,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CastResult {
+    UnknownSpell,
+    LevelTooLow {
+        required: u32,
+    },
+    OnCooldown {
+        turns_left: u32,
+    },
+    InsufficientMana {
+        cost: u32,
+        mana: u32,
+    },
+    /// The caster has no mana pool, so the spell cost health and did nothing.
+    /// `health_lost` is what got past any shield.
+    PaidInHealth {
+        health_lost: u32,
+    },
+    /// The cost was paid; `effects` are still to be applied to `target`.
+    Success {
+        target: Target,
+        effects: Vec<Effect>,
+    },
+}
+
+impl CastResult {
+    /// The total damage of a successful cast.
+    pub fn damage(&self) -> u32 {
+        match self {
+            CastResult::Success { effects, .. } => effects
+                .iter()
+                .map(|effect| match effect {
+                    Effect::Damage(amount) => *amount,
+                    Effect::Heal(_) | Effect::Status(_) => 0,
+                })
+                .sum(),
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    spell: Spell,
+    turns_left: u32,
+}
+
+/// The spells a player knows, and how long until each can be cast again.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Spellbook {
+    entries: Vec<Entry>,
+}
+
+impl Spellbook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `spell`, returning the spell it replaced if one had the same name.
+    pub fn learn(&mut self, spell: Spell) -> Option<Spell> {
+        let entry = Entry {
+            spell,
+            turns_left: 0,
+        };
+        match self.position(&entry.spell.name) {
+            Some(i) => Some(std::mem::replace(&mut self.entries[i], entry).spell),
+            None => {
+                self.entries.push(entry);
+                None
+            }
+        }
+    }
+
+    pub fn forget(&mut self, name: &str) -> Option<Spell> {
+        self.position(name).map(|i| self.entries.remove(i).spell)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Spell> {
+        self.position(name).map(|i| &self.entries[i].spell)
+    }
+
+    pub fn spells(&self) -> impl Iterator<Item = &Spell> {
+        self.entries.iter().map(|entry| &entry.spell)
+    }
+
+    /// Turns left until `name` can be cast again; 0 if it's ready or unknown.
+    pub fn cooldown(&self, name: &str) -> u32 {
+        self.position(name)
+            .map_or(0, |i| self.entries[i].turns_left)
+    }
+
+    /// Counts every cooldown down by one turn. Call at the start of the owner's turn.
+    pub fn tick(&mut self) {
+        for entry in &mut self.entries {
+            entry.turns_left = entry.turns_left.saturating_sub(1);
+        }
+    }
+
+    pub(crate) fn start_cooldown(&mut self, name: &str) {
+        if let Some(i) = self.position(name) {
+            self.entries[i].turns_left = self.entries[i].spell.cooldown;
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.spell.name == name)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::Player;
+
+/// What a status effect does, and how a second one of the same kind stacks with it.
+///
+/// A stacked effect always lasts as long as the longer of the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Deals `potency` damage at the start of each turn, which a shield can
+    /// absorb; potencies add up.
+    Poison,
+    /// Gains `potency` health at the start of each turn; the stronger one wins.
+    Regen,
+    /// Skips turns.
+    Stun,
+    /// Absorbs up to `potency` damage before health is lost; potencies add up.
+    Shield,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub potency: u32,
+    /// How many more of its owner's turns the effect lasts.
+    pub turns: u32,
+}
+
+impl StatusEffect {
+    pub fn poison(damage: u32, turns: u32) -> Self {
+        StatusEffect {
+            kind: StatusKind::Poison,
+            potency: damage,
+            turns,
+        }
+    }
+
+    pub fn regen(heal: u32, turns: u32) -> Self {
+        StatusEffect {
+            kind: StatusKind::Regen,
+            potency: heal,
+            turns,
+        }
+    }
+
+    pub fn stun(turns: u32) -> Self {
+        StatusEffect {
+            kind: StatusKind::Stun,
+            potency: 0,
+            turns,
+        }
+    }
+
+    pub fn shield(amount: u32, turns: u32) -> Self {
+        StatusEffect {
+            kind: StatusKind::Shield,
+            potency: amount,
+            turns,
+        }
+    }
+}
+
+/// What happened to a player's statuses at the start of their turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEvent {
+    /// `damage` is the health lost, after any shield.
+    Poisoned {
+        damage: u32,
+    },
+    Regenerated {
+        healed: u32,
+    },
+    /// The player loses this turn.
+    Stunned,
+    Expired(StatusKind),
+}
+
+/// The status effects on a player, at most one of each kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statuses {
+    effects: Vec<StatusEffect>,
+}
+
+impl Statuses {
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Adds `effect`, stacking it with one of the same kind as [`StatusKind`] describes.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        if effect.turns == 0 {
+            return;
+        }
+        match self.effects.iter_mut().find(|e| e.kind == effect.kind) {
+            Some(current) => {
+                current.turns = current.turns.max(effect.turns);
+                current.potency = match effect.kind {
+                    StatusKind::Poison | StatusKind::Shield => {
+                        current.potency.saturating_add(effect.potency)
+                    }
+                    StatusKind::Regen | StatusKind::Stun => current.potency.max(effect.potency),
+                };
+            }
+            None => self.effects.push(effect),
+        }
+    }
+
+    pub fn remove(&mut self, kind: StatusKind) -> Option<StatusEffect> {
+        let i = self.effects.iter().position(|effect| effect.kind == kind)?;
+        Some(self.effects.remove(i))
+    }
+
+    /// Takes what it can of `damage` out of the shield, returning the rest.
+    pub(crate) fn absorb(&mut self, damage: u32) -> u32 {
+        let shield = match self
+            .effects
+            .iter_mut()
+            .find(|effect| effect.kind == StatusKind::Shield)
+        {
+            Some(shield) => shield,
+            None => return damage,
+        };
+        let absorbed = shield.potency.min(damage);
+        shield.potency -= absorbed;
+        if shield.potency == 0 {
+            self.remove(StatusKind::Shield);
+        }
+        damage - absorbed
+    }
+}
+
+impl Player {
+    /// Loses `damage` health, less whatever a shield absorbs, and returns the health lost.
+    pub fn take_damage(&mut self, damage: u32) -> u32 {
+        let lost = self.statuses.absorb(damage).min(self.health);
+        self.health -= lost;
+        lost
+    }
+
+    /// Runs poison, regen and stun for the start of the player's turn and
+    /// counts every effect down by one turn.
+    pub fn tick_statuses(&mut self) -> Vec<StatusEvent> {
+        let mut events = vec![];
+        for effect in self.statuses.effects.clone() {
+            match effect.kind {
+                StatusKind::Poison => {
+                    let damage = self.take_damage(effect.potency);
+                    events.push(StatusEvent::Poisoned { damage });
+                }
+                StatusKind::Regen => {
+                    let healed = effect
+                        .potency
+                        .min(self.max_health_with_gear().saturating_sub(self.health));
+                    self.health += healed;
+                    events.push(StatusEvent::Regenerated { healed });
+                }
+                StatusKind::Stun => events.push(StatusEvent::Stunned),
+                StatusKind::Shield => {}
+            }
+        }
+        self.statuses.effects.retain_mut(|effect| {
+            effect.turns -= 1;
+            if effect.turns == 0 {
+                events.push(StatusEvent::Expired(effect.kind));
+            }
+            effect.turns > 0
+        });
+        events
+    }
+}
@@ -0,0 +1,144 @@
+use rand::rngs::mock::StepRng;
+use role_playing_game::combat::ATTACK_DAMAGE;
+use role_playing_game::*;
+
+fn player(health: u32, mana: Option<u32>, level: u32) -> Player {
+    Player {
+        health,
+        mana,
+        level,
+        ..Default::default()
+    }
+}
+
+// always hits and always rolls the lowest damage
+fn lowest_rolls() -> StepRng {
+    StepRng::new(0, 0)
+}
+
+#[test]
+fn test_attack_hits_opponent() {
+    let mut battle = Battle::with_rng(player(50, None, 1), player(50, None, 1), lowest_rolls());
+    assert_eq!(battle.take_turn(Action::Attack), None);
+    let damage = *ATTACK_DAMAGE.start();
+    assert_eq!(battle.defender().health, 50 - damage);
+    assert_eq!(
+        battle.log(),
+        [
+            Event::Turn {
+                number: 1,
+                side: Side::Attacker
+            },
+            Event::Hit {
+                by: Side::Attacker,
+                damage
+            },
+        ]
+    );
+    assert_eq!(battle.side_to_act(), Side::Defender);
+}
+
+#[test]
+fn test_level_adds_attack_damage() {
+    let mut battle = Battle::with_rng(player(50, None, 20), player(50, None, 1), lowest_rolls());
+    battle.take_turn(Action::Attack);
+    assert_eq!(battle.defender().health, 50 - ATTACK_DAMAGE.start() - 4);
+}
+
+#[test]
+fn test_defending_halves_damage_until_next_turn() {
+    let mut battle = Battle::with_rng(player(50, None, 1), player(50, None, 1), lowest_rolls());
+    battle.take_turn(Action::Defend);
+    battle.take_turn(Action::Attack);
+    assert_eq!(battle.attacker().health, 50 - ATTACK_DAMAGE.start() / 2);
+    battle.take_turn(Action::Attack);
+    battle.take_turn(Action::Attack);
+    assert_eq!(
+        battle.attacker().health,
+        50 - ATTACK_DAMAGE.start() / 2 - ATTACK_DAMAGE.start()
+    );
+}
+
+#[test]
+fn test_spell_damages_opponent() {
+    let mut battle = Battle::new(player(50, Some(100), 10), player(50, None, 1), 7);
+    battle.take_turn(Action::CastSpell(10));
+    assert_eq!(battle.defender().health, 30);
+    assert_eq!(battle.attacker().mana, Some(90));
+    assert_eq!(
+        battle.log().last(),
+        Some(&Event::Spell {
+            by: Side::Attacker,
+            mana_cost: 10,
+            damage: 20
+        })
+    );
+}
+
+#[test]
+fn test_spell_without_mana_pool_can_be_fatal() {
+    let mut battle = Battle::new(player(5, None, 1), player(50, None, 1), 7);
+    assert_eq!(
+        battle.take_turn(Action::CastSpell(10)),
+        Some(BattleOutcome::Victory(Side::Defender))
+    );
+    assert_eq!(
+        battle.log().last(),
+        Some(&Event::Defeated {
+            side: Side::Attacker
+        })
+    );
+    assert_eq!(battle.defender().health, 50);
+}
+
+#[test]
+fn test_no_turns_after_outcome() {
+    let mut battle = Battle::new(player(50, Some(100), 10), player(10, None, 1), 7);
+    let outcome = Some(BattleOutcome::Victory(Side::Attacker));
+    assert_eq!(battle.take_turn(Action::CastSpell(5)), outcome);
+    let log = battle.log().to_vec();
+    assert_eq!(battle.take_turn(Action::Attack), outcome);
+    assert_eq!(battle.log(), log);
+}
+
+#[test]
+fn test_dead_player_loses_immediately() {
+    let battle = Battle::new(player(50, None, 1), player(0, None, 1), 7);
+    assert_eq!(
+        battle.outcome(),
+        Some(BattleOutcome::Victory(Side::Attacker))
+    );
+    let battle = Battle::new(player(0, None, 1), player(0, None, 1), 7);
+    assert_eq!(battle.outcome(), Some(BattleOutcome::Draw));
+}
+
+#[test]
+fn test_turn_limit_is_a_draw() {
+    let mut battle = Battle::new(player(50, None, 1), player(50, None, 1), 7).with_max_turns(4);
+    let outcome = battle.fight(|_, _, _| Action::Defend);
+    assert_eq!(outcome, BattleOutcome::Draw);
+    assert_eq!(battle.turn(), 4);
+}
+
+#[test]
+fn test_fight_runs_to_a_victory() {
+    let mut battle = Battle::new(player(100, None, 1), player(100, Some(100), 30), 42);
+    let outcome = battle.fight(|_, me, _| match me.mana {
+        Some(mana) if mana > 20 => Action::CastSpell(20),
+        _ => Action::Attack,
+    });
+    assert_eq!(outcome, BattleOutcome::Victory(Side::Defender));
+    assert_eq!(battle.attacker().health, 0);
+    assert!(battle.defender().health > 0);
+}
+
+#[test]
+fn test_same_seed_same_battle() {
+    let fight = |seed| {
+        let mut battle = Battle::new(player(100, None, 5), player(100, None, 5), seed);
+        let outcome = battle.fight(|_, _, _| Action::Attack);
+        (outcome, battle.log().to_vec())
+    };
+    assert_eq!(fight(1), fight(1));
+    assert_ne!(fight(1).1, fight(2).1);
+}
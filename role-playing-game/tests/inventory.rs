@@ -0,0 +1,319 @@
+use rand::rngs::mock::StepRng;
+use role_playing_game::combat::ATTACK_DAMAGE;
+use role_playing_game::*;
+
+fn sword() -> Item {
+    Item::equipment(
+        "sword",
+        8,
+        Slot::Weapon,
+        Stats {
+            attack: 5,
+            ..Default::default()
+        },
+    )
+}
+
+fn plate() -> Item {
+    Item::equipment(
+        "plate",
+        30,
+        Slot::Armor,
+        Stats {
+            defense: 3,
+            max_health: 20,
+            ..Default::default()
+        },
+    )
+    .with_min_level(5)
+}
+
+#[test]
+fn test_consumables_stack() {
+    let mut inventory = Inventory::new(20);
+    inventory.add(Item::potion("tonic", 20, 0), 3).unwrap();
+    inventory.add(Item::potion("tonic", 20, 0), 2).unwrap();
+    inventory.add(sword(), 1).unwrap();
+    assert_eq!(inventory.count("tonic"), 5);
+    assert_eq!(inventory.items().count(), 2);
+    assert_eq!(inventory.weight(), 13);
+    assert_eq!(inventory.remove("tonic").unwrap().name, "tonic");
+    assert_eq!(inventory.count("tonic"), 4);
+}
+
+#[test]
+fn test_capacity_limit() {
+    let mut inventory = Inventory::new(10);
+    inventory.add(sword(), 1).unwrap();
+    assert_eq!(
+        inventory.add(Item::potion("tonic", 20, 0), 3),
+        Err(InventoryError::Overweight {
+            weight: 11,
+            capacity: 10
+        })
+    );
+    assert_eq!(inventory.count("tonic"), 0);
+    assert_eq!(
+        inventory.remove("tonic"),
+        Err(InventoryError::NotFound("tonic".to_string()))
+    );
+}
+
+#[test]
+fn test_adding_none_does_nothing() {
+    let mut inventory = Inventory::new(10);
+    inventory.add(Item::potion("tonic", 20, 0), 0).unwrap();
+    assert_eq!(inventory.items().count(), 0);
+    assert_eq!(
+        inventory.remove("tonic"),
+        Err(InventoryError::NotFound("tonic".to_string()))
+    );
+}
+
+#[test]
+fn test_weightless_stacks_are_bounded() {
+    let mut inventory = Inventory::new(10);
+    let mut feather = Item::phoenix_feather();
+    feather.weight = 0;
+    inventory.add(feather.clone(), u32::MAX).unwrap();
+    assert_eq!(
+        inventory.add(feather.clone(), 1),
+        Err(InventoryError::StackFull("phoenix feather".to_string()))
+    );
+    assert_eq!(inventory.count("phoenix feather"), u32::MAX);
+
+    // a different item under the same name
+    let mut heavy = feather.clone();
+    heavy.weight = 5;
+    inventory.add(heavy, 2).unwrap();
+    assert_eq!(inventory.count("phoenix feather"), u32::MAX);
+    assert_eq!(inventory.weight(), 10);
+}
+
+#[test]
+fn test_equipping_modifies_stats() {
+    let mut player = Player {
+        level: 5,
+        ..Default::default()
+    };
+    player.inventory.add(plate(), 1).unwrap();
+    player.inventory.add(sword(), 2).unwrap();
+    player.equip("plate").unwrap();
+    player.equip("sword").unwrap();
+    assert_eq!(player.inventory.equipped(Slot::Weapon), Some(&sword()));
+    assert_eq!(player.inventory.count("sword"), 1);
+    assert_eq!(player.inventory.weight(), 46);
+    assert_eq!(
+        player.inventory.bonus(),
+        Stats {
+            attack: 5,
+            defense: 3,
+            max_health: 20,
+            max_mana: 0
+        }
+    );
+    assert_eq!(player.max_health_with_gear(), 120);
+}
+
+#[test]
+fn test_equipping_swaps_gear() {
+    let mut player = Player::default();
+    let axe = Item::equipment("axe", 10, Slot::Weapon, Stats::default());
+    player.inventory.add(sword(), 1).unwrap();
+    player.inventory.add(axe.clone(), 1).unwrap();
+    player.equip("sword").unwrap();
+    player.equip("axe").unwrap();
+    assert_eq!(player.inventory.equipped(Slot::Weapon), Some(&axe));
+    assert_eq!(player.inventory.count("sword"), 1);
+    assert_eq!(player.unequip(Slot::Weapon), Some(&axe));
+    assert_eq!(player.inventory.equipped(Slot::Weapon), None);
+    assert_eq!(player.inventory.weight(), 18);
+}
+
+#[test]
+fn test_unequipping_caps_health_and_mana() {
+    let mut player = Player {
+        level: 10,
+        mana: Some(100),
+        ..Default::default()
+    };
+    let robe = Item::equipment(
+        "robe",
+        2,
+        Slot::Armor,
+        Stats {
+            max_mana: 30,
+            ..Default::default()
+        },
+    );
+    player.inventory.add(plate(), 1).unwrap();
+    player.inventory.add(robe, 1).unwrap();
+    player.equip("plate").unwrap();
+    player.health = 120;
+    player.equip("robe").unwrap();
+    assert_eq!(player.health, 100);
+    player.mana = Some(130);
+    assert_eq!(
+        player.unequip(Slot::Armor).map(|item| item.name.as_str()),
+        Some("robe")
+    );
+    assert_eq!(player.mana, Some(100));
+    assert_eq!(player.unequip(Slot::Armor), None);
+}
+
+#[test]
+fn test_bonuses_saturate() {
+    let mut player = Player::default();
+    for (name, slot) in [("charm", Slot::Trinket), ("crown", Slot::Armor)] {
+        let bonus = Stats {
+            max_health: u32::MAX,
+            ..Default::default()
+        };
+        player
+            .inventory
+            .add(Item::equipment(name, 0, slot, bonus), 1)
+            .unwrap();
+        player.equip(name).unwrap();
+    }
+    assert_eq!(player.inventory.bonus().max_health, u32::MAX);
+    assert_eq!(player.max_health_with_gear(), u32::MAX);
+}
+
+#[test]
+fn test_invalid_equips() {
+    let mut player = Player::default();
+    player.inventory.add(plate(), 1).unwrap();
+    player
+        .inventory
+        .add(Item::potion("tonic", 20, 0), 1)
+        .unwrap();
+    assert_eq!(
+        player.equip("plate"),
+        Err(InventoryError::LevelTooLow { required: 5 })
+    );
+    assert_eq!(
+        player.equip("tonic"),
+        Err(InventoryError::NotEquipment("tonic".to_string()))
+    );
+    assert_eq!(
+        player.equip("sword"),
+        Err(InventoryError::NotFound("sword".to_string()))
+    );
+    assert_eq!(player.inventory.equipped(Slot::Armor), None);
+    assert_eq!(player.inventory.count("plate"), 1);
+}
+
+#[test]
+fn test_potions_restore_up_to_max() {
+    let mut player = Player {
+        health: 70,
+        mana: Some(90),
+        level: 10,
+        ..Default::default()
+    };
+    let elixir = Item::potion("elixir", 50, 50).with_status(StatusEffect::regen(5, 2));
+    player.inventory.add(elixir, 2).unwrap();
+    player.use_item("elixir").unwrap();
+    assert_eq!(player.health, 100);
+    assert_eq!(player.mana, Some(100));
+    assert_eq!(
+        player.statuses.get(StatusKind::Regen),
+        Some(&StatusEffect::regen(5, 2))
+    );
+    assert_eq!(player.inventory.count("elixir"), 1);
+}
+
+#[test]
+fn test_potion_does_not_give_a_mana_pool() {
+    let mut player = Player {
+        health: 50,
+        ..Default::default()
+    };
+    player
+        .inventory
+        .add(Item::potion("ether", 0, 30), 1)
+        .unwrap();
+    player.use_item("ether").unwrap();
+    assert_eq!(player.mana, None);
+}
+
+#[test]
+fn test_phoenix_feather_revives() {
+    let mut player = Player {
+        health: 0,
+        mana: Some(0),
+        level: 12,
+        ..Default::default()
+    };
+    player.inventory.add(Item::phoenix_feather(), 1).unwrap();
+    player
+        .inventory
+        .add(Item::potion("tonic", 20, 0), 1)
+        .unwrap();
+    assert_eq!(
+        player.use_item("tonic"),
+        Err(InventoryError::CannotUse("tonic".to_string()))
+    );
+    player.use_item("phoenix feather").unwrap();
+    assert_eq!(player.health, 100);
+    assert_eq!(player.mana, Some(100));
+    assert_eq!(player.inventory.count("phoenix feather"), 0);
+    assert_eq!(player.inventory.count("tonic"), 1);
+    assert_eq!(
+        player.use_item("phoenix feather"),
+        Err(InventoryError::NotFound("phoenix feather".to_string()))
+    );
+}
+
+#[test]
+fn test_phoenix_feather_needs_a_dead_player() {
+    let mut player = Player::default();
+    player.inventory.add(Item::phoenix_feather(), 1).unwrap();
+    assert_eq!(
+        player.use_item("phoenix feather"),
+        Err(InventoryError::CannotUse("phoenix feather".to_string()))
+    );
+    assert!(!player.try_phoenix_feather());
+    assert_eq!(player.inventory.count("phoenix feather"), 1);
+}
+
+#[test]
+fn test_battle_uses_gear_and_feathers() {
+    let mut attacker = Player {
+        health: 5,
+        mana: None,
+        level: 1,
+        ..Default::default()
+    };
+    attacker.inventory.add(Item::phoenix_feather(), 1).unwrap();
+    let mut defender = Player {
+        level: 5,
+        ..Default::default()
+    };
+    defender.inventory.add(plate(), 1).unwrap();
+    defender.equip("plate").unwrap();
+    // always hits and always rolls the lowest damage
+    let mut battle = Battle::with_rng(attacker, defender, StepRng::new(0, 0));
+
+    assert_eq!(battle.take_turn(Action::CastSpell(10)), None);
+    assert_eq!(
+        battle.log().last(),
+        Some(&Event::Revived {
+            side: Side::Attacker
+        })
+    );
+    assert_eq!(battle.attacker().health, 100);
+
+    battle.take_turn(Action::UseItem("tonic".to_string()));
+    assert_eq!(
+        battle.log().last(),
+        Some(&Event::UseItem {
+            by: Side::Defender,
+            item: "tonic".to_string(),
+            result: Err(InventoryError::NotFound("tonic".to_string()))
+        })
+    );
+
+    battle.take_turn(Action::Attack);
+    assert_eq!(battle.defender().health, 100 - (ATTACK_DAMAGE.start() - 3));
+}
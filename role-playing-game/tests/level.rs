@@ -0,0 +1,182 @@
+use role_playing_game::level::{HEALTH_PER_LEVEL, MANA_PER_LEVEL, MAX_LEVEL};
+use role_playing_game::*;
+
+#[test]
+fn test_linear_curve() {
+    let curve = XpCurve::linear(100).unwrap();
+    assert_eq!(curve.xp_for_level(1), Some(0));
+    assert_eq!(curve.xp_for_level(2), Some(100));
+    assert_eq!(curve.xp_for_level(11), Some(1000));
+    assert_eq!(curve.level_for_xp(99), 1);
+    assert_eq!(curve.level_for_xp(1050), 11);
+}
+
+#[test]
+fn test_exponential_curve() {
+    let curve = XpCurve::exponential(100, 1.5).unwrap();
+    assert_eq!(curve.xp_for_level(2), Some(100));
+    assert_eq!(curve.xp_for_level(3), Some(250));
+    assert_eq!(curve.xp_for_level(4), Some(475));
+    assert_eq!(curve.xp_for_level(1000), None);
+}
+
+#[test]
+fn test_table_curve_from_file() {
+    let curve = XpCurve::load_table("tests/data/xp.txt").unwrap();
+    assert_eq!(curve, XpCurve::table(vec![100, 250, 450, 700]).unwrap());
+    assert_eq!(curve.xp_for_level(5), Some(700));
+    assert_eq!(curve.xp_for_level(6), None);
+    assert_eq!(curve.level_for_xp(u64::MAX), 5);
+}
+
+#[test]
+fn test_invalid_tables() {
+    assert!(matches!(
+        XpCurve::parse_table("100\nlots\n"),
+        Err(CurveError::InvalidNumber { line: 2, .. })
+    ));
+    assert!(matches!(
+        XpCurve::parse_table("100\n250\n250\n"),
+        Err(CurveError::NotIncreasing { level: 4 })
+    ));
+    assert!(matches!(
+        XpCurve::parse_table("# nothing yet\n"),
+        Err(CurveError::Empty)
+    ));
+    assert!(matches!(
+        XpCurve::load_table("tests/data/missing.txt"),
+        Err(CurveError::Io(_))
+    ));
+}
+
+#[test]
+fn test_curves_must_grow() {
+    assert!(matches!(XpCurve::linear(0), Err(CurveError::Flat)));
+    assert!(matches!(
+        XpCurve::exponential(0, 1.5),
+        Err(CurveError::Flat)
+    ));
+    for factor in [0.5, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            XpCurve::exponential(100, factor),
+            Err(CurveError::InvalidFactor(_))
+        ));
+    }
+    assert!(matches!(XpCurve::table(vec![]), Err(CurveError::Empty)));
+}
+
+#[test]
+fn test_levels_stop_at_max_level() {
+    let curve = XpCurve::linear(1).unwrap();
+    assert_eq!(curve.xp_for_level(MAX_LEVEL), Some(MAX_LEVEL as u64 - 1));
+    assert_eq!(curve.xp_for_level(MAX_LEVEL + 1), None);
+    assert_eq!(curve.level_for_xp(u64::MAX), MAX_LEVEL);
+    let curve = XpCurve::exponential(1, 1.0).unwrap();
+    assert_eq!(curve.level_for_xp(u64::MAX), MAX_LEVEL);
+
+    let mut player = Player::default();
+    assert_eq!(
+        player.gain_xp(u64::MAX, &curve).len(),
+        MAX_LEVEL as usize - 1
+    );
+    assert_eq!(player.level, MAX_LEVEL);
+    assert_eq!(player.gain_xp(u64::MAX, &curve), []);
+}
+
+#[test]
+fn test_gaining_xp_levels_up() {
+    let curve = XpCurve::linear(100).unwrap();
+    let mut player = Player {
+        health: 40,
+        ..Default::default()
+    };
+    assert_eq!(player.gain_xp(50, &curve), []);
+    let level_ups = player.gain_xp(200, &curve);
+    assert_eq!(
+        level_ups,
+        [
+            LevelUp {
+                level: 2,
+                max_health: 100 + HEALTH_PER_LEVEL,
+                max_mana: 100 + MANA_PER_LEVEL,
+                mana_unlocked: false
+            },
+            LevelUp {
+                level: 3,
+                max_health: 100 + 2 * HEALTH_PER_LEVEL,
+                max_mana: 100 + 2 * MANA_PER_LEVEL,
+                mana_unlocked: false
+            },
+        ]
+    );
+    assert_eq!(player.xp, 250);
+    assert_eq!(player.level, 3);
+    assert_eq!(player.health, 40 + 2 * HEALTH_PER_LEVEL);
+    assert_eq!(player.mana, None);
+}
+
+#[test]
+fn test_mana_unlocks_at_level_10() {
+    let curve = XpCurve::linear(10).unwrap();
+    let mut player = Player::default();
+    let level_ups = player.gain_xp(100, &curve);
+    assert_eq!(level_ups.len(), 10);
+    assert!(level_ups[..8]
+        .iter()
+        .all(|level_up| !level_up.mana_unlocked));
+    assert!(level_ups[8].mana_unlocked);
+    assert_eq!(level_ups[8].level, 10);
+    assert_eq!(player.level, 11);
+    assert_eq!(player.max_mana, 100 + 10 * MANA_PER_LEVEL);
+    assert_eq!(player.mana, Some(player.max_mana));
+}
+
+#[test]
+fn test_table_stops_at_last_level() {
+    let curve = XpCurve::table(vec![10, 20]).unwrap();
+    let mut player = Player::default();
+    assert_eq!(player.gain_xp(1000, &curve).len(), 2);
+    assert_eq!(player.level, 3);
+    assert_eq!(player.xp, 1000);
+}
+
+#[test]
+fn test_revive_restores_max_stats() {
+    let curve = XpCurve::linear(10).unwrap();
+    let mut player = Player::default();
+    player.gain_xp(100, &curve);
+    player.health = 0;
+    let revived = player.revive().unwrap();
+    assert_eq!(revived.health, 100 + 10 * HEALTH_PER_LEVEL);
+    assert_eq!(revived.mana, Some(100 + 10 * MANA_PER_LEVEL));
+    assert_eq!(revived.xp, 100);
+}
+
+#[test]
+fn test_healing_stops_at_max_health() {
+    let mut player = Player {
+        health: 95,
+        mana: Some(50),
+        level: 10,
+        ..Default::default()
+    };
+    player
+        .spellbook
+        .learn(Spell::new("mend", 5, SpellEffect::Heal(Formula::fixed(15))));
+    if let CastResult::Success { effects, .. } = player.cast("mend") {
+        effects.into_iter().for_each(|effect| player.apply(effect));
+    }
+    assert_eq!(player.health, 100);
+}
+
+#[test]
+fn test_gaining_xp_does_not_revive() {
+    let curve = XpCurve::linear(10).unwrap();
+    let mut player = Player {
+        health: 0,
+        ..Default::default()
+    };
+    assert_eq!(player.gain_xp(30, &curve).len(), 3);
+    assert_eq!(player.health, 0);
+    assert_eq!(player.max_health, 100 + 3 * HEALTH_PER_LEVEL);
+}
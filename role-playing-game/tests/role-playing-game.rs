@@ -6,6 +6,7 @@ fn test_reviving_dead_player() {
         health: 0,
         mana: Some(0),
         level: 34,
+        ..Default::default()
     };
     let revived_player = dead_player
         .revive()
@@ -21,6 +22,7 @@ fn test_reviving_dead_level9_player() {
         health: 0,
         mana: None,
         level: 9,
+        ..Default::default()
     };
     let revived_player = dead_player
         .revive()
@@ -36,6 +38,7 @@ fn test_reviving_dead_level10_player() {
         health: 0,
         mana: Some(0),
         level: 10,
+        ..Default::default()
     };
     let revived_player = dead_player
         .revive()
@@ -51,6 +54,7 @@ fn test_reviving_alive_player() {
         health: 1,
         mana: None,
         level: 8,
+        ..Default::default()
     };
     assert!(alive_player.revive().is_none());
 }
@@ -66,6 +70,7 @@ fn test_cast_spell_with_enough_mana() {
         health: HEALTH,
         mana: Some(MANA),
         level: LEVEL,
+        ..Default::default()
     };
 
     assert_eq!(accomplished_wizard.cast_spell(MANA_COST), MANA_COST * 2);
@@ -80,11 +85,11 @@ fn test_cast_spell_with_insufficient_mana() {
         health: 56,
         mana: Some(2),
         level: 22,
+        ..Default::default()
     };
 
-    // we want to clone so we can compare before-and-after effects of casting the spell,
-    // but we don't want to introduce that concept to the student yet, so we have to do it manually
-    let clone = Player { ..no_mana_wizard };
+    // clone so we can compare before-and-after effects of casting the spell
+    let clone = no_mana_wizard.clone();
 
     assert_eq!(no_mana_wizard.cast_spell(3), 0);
     assert_eq!(no_mana_wizard.health, clone.health);
@@ -100,11 +105,10 @@ fn test_cast_spell_with_no_mana_pool() {
         health: 87,
         mana: None,
         level: 6,
+        ..Default::default()
     };
 
-    let clone = Player {
-        ..underleveled_player
-    };
+    let clone = underleveled_player.clone();
 
     assert_eq!(underleveled_player.cast_spell(MANA_COST), 0);
     assert_eq!(underleveled_player.health, clone.health - MANA_COST);
@@ -120,6 +124,7 @@ fn test_cast_large_spell_with_no_mana_pool() {
         health: 20,
         mana: None,
         level: 6,
+        ..Default::default()
     };
 
     assert_eq!(underleveled_player.cast_spell(MANA_COST), 0);
@@ -0,0 +1,185 @@
+use role_playing_game::*;
+
+fn wizard(mana: u32, level: u32) -> Player {
+    Player {
+        health: 50,
+        mana: Some(mana),
+        level,
+        ..Default::default()
+    }
+}
+
+fn fireball() -> Spell {
+    Spell::new(
+        "fireball",
+        20,
+        SpellEffect::Damage(Formula {
+            base: 10,
+            per_level: 2,
+        }),
+    )
+    .with_cooldown(2)
+    .with_min_level(12)
+}
+
+fn mend() -> Spell {
+    Spell::new("mend", 5, SpellEffect::Heal(Formula::fixed(15)))
+}
+
+#[test]
+fn test_arcane_bolt_is_cast_spell() {
+    let mut player = wizard(30, 18);
+    assert_eq!(
+        Spell::arcane_bolt(10).cast(&mut player),
+        CastResult::Success {
+            target: Target::Opponent,
+            effects: vec![Effect::Damage(20)]
+        }
+    );
+    assert_eq!(player.mana, Some(20));
+}
+
+#[test]
+fn test_cast_spell_with_exactly_enough_mana() {
+    let mut player = wizard(10, 18);
+    assert_eq!(player.cast_spell(10), 20);
+    assert_eq!(player.mana, Some(0));
+}
+
+#[test]
+fn test_formula_scales_with_level() {
+    let mut player = wizard(100, 15);
+    player.spellbook.learn(fireball());
+    assert_eq!(player.cast("fireball").damage(), 40);
+    assert_eq!(player.mana, Some(80));
+}
+
+#[test]
+fn test_level_requirement() {
+    let mut player = wizard(100, 11);
+    player.spellbook.learn(fireball());
+    assert_eq!(
+        player.cast("fireball"),
+        CastResult::LevelTooLow { required: 12 }
+    );
+    assert_eq!(player.mana, Some(100));
+}
+
+#[test]
+fn test_insufficient_mana_costs_nothing() {
+    let mut player = wizard(19, 20);
+    player.spellbook.learn(fireball());
+    assert_eq!(
+        player.cast("fireball"),
+        CastResult::InsufficientMana { cost: 20, mana: 19 }
+    );
+    assert_eq!(player.mana, Some(19));
+    assert_eq!(player.spellbook.cooldown("fireball"), 0);
+}
+
+#[test]
+fn test_no_mana_pool_pays_in_health() {
+    let mut player = Player {
+        health: 8,
+        mana: None,
+        level: 3,
+        ..Default::default()
+    };
+    player.spellbook.learn(mend());
+    assert_eq!(
+        player.cast("mend"),
+        CastResult::PaidInHealth { health_lost: 5 }
+    );
+    assert_eq!(
+        player.cast("mend"),
+        CastResult::PaidInHealth { health_lost: 3 }
+    );
+    assert_eq!(player.health, 0);
+}
+
+#[test]
+fn test_unknown_spell() {
+    let mut player = wizard(100, 20);
+    assert_eq!(player.cast("fireball"), CastResult::UnknownSpell);
+}
+
+#[test]
+fn test_cooldown_counts_down_per_tick() {
+    let mut player = wizard(100, 20);
+    player.spellbook.learn(fireball());
+    assert!(matches!(
+        player.cast("fireball"),
+        CastResult::Success { .. }
+    ));
+    assert_eq!(
+        player.cast("fireball"),
+        CastResult::OnCooldown { turns_left: 2 }
+    );
+    player.spellbook.tick();
+    assert_eq!(
+        player.cast("fireball"),
+        CastResult::OnCooldown { turns_left: 1 }
+    );
+    player.spellbook.tick();
+    assert!(matches!(
+        player.cast("fireball"),
+        CastResult::Success { .. }
+    ));
+    assert_eq!(player.mana, Some(60));
+}
+
+#[test]
+fn test_learning_replaces_by_name() {
+    let mut spellbook = Spellbook::new();
+    assert_eq!(spellbook.learn(fireball()), None);
+    assert_eq!(spellbook.learn(mend()), None);
+    let cheap = fireball().with_cooldown(0);
+    assert_eq!(spellbook.learn(cheap.clone()), Some(fireball()));
+    assert_eq!(spellbook.get("fireball"), Some(&cheap));
+    assert_eq!(spellbook.spells().count(), 2);
+    assert_eq!(spellbook.forget("mend"), Some(mend()));
+    assert_eq!(spellbook.get("mend"), None);
+}
+
+#[test]
+fn test_battle_applies_spell_effects() {
+    let mut caster = wizard(100, 20);
+    caster.spellbook.learn(fireball());
+    caster.spellbook.learn(mend());
+    let mut battle = Battle::new(caster, wizard(0, 1), 3);
+
+    battle.take_turn(Action::Cast("fireball".to_string()));
+    assert_eq!(battle.defender().health, 0);
+    assert_eq!(
+        battle.log()[2..],
+        [
+            Event::Effect {
+                side: Side::Defender,
+                effect: Effect::Damage(50)
+            },
+            Event::Defeated {
+                side: Side::Defender
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_battle_heals_caster_and_ticks_cooldowns() {
+    let mut caster = wizard(100, 20);
+    caster.spellbook.learn(fireball());
+    caster.spellbook.learn(mend());
+    let defender = Player {
+        health: 200,
+        ..Default::default()
+    };
+    let mut battle = Battle::new(caster, defender, 3);
+
+    battle.take_turn(Action::Cast("fireball".to_string()));
+    battle.take_turn(Action::Defend);
+    battle.take_turn(Action::Cast("mend".to_string()));
+    assert_eq!(battle.attacker().health, 65);
+    battle.take_turn(Action::Defend);
+    battle.take_turn(Action::Cast("fireball".to_string()));
+    assert_eq!(battle.defender().health, 200 - 50 - 50 / 2);
+}
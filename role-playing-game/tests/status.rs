@@ -0,0 +1,223 @@
+use role_playing_game::*;
+
+#[test]
+fn test_poison_stacks_potency() {
+    let mut statuses = Statuses::default();
+    statuses.apply(StatusEffect::poison(3, 2));
+    statuses.apply(StatusEffect::poison(2, 4));
+    assert_eq!(
+        statuses.get(StatusKind::Poison),
+        Some(&StatusEffect::poison(5, 4))
+    );
+}
+
+#[test]
+fn test_regen_keeps_the_stronger() {
+    let mut statuses = Statuses::default();
+    statuses.apply(StatusEffect::regen(10, 1));
+    statuses.apply(StatusEffect::regen(4, 3));
+    assert_eq!(
+        statuses.get(StatusKind::Regen),
+        Some(&StatusEffect::regen(10, 3))
+    );
+}
+
+#[test]
+fn test_stun_does_not_chain() {
+    let mut statuses = Statuses::default();
+    statuses.apply(StatusEffect::stun(2));
+    statuses.apply(StatusEffect::stun(1));
+    assert_eq!(statuses.get(StatusKind::Stun), Some(&StatusEffect::stun(2)));
+    statuses.apply(StatusEffect::stun(0));
+    assert_eq!(statuses.iter().count(), 1);
+}
+
+#[test]
+fn test_ticks_run_and_expire_effects() {
+    let mut player = Player {
+        health: 50,
+        ..Default::default()
+    };
+    player.statuses.apply(StatusEffect::poison(5, 2));
+    player.statuses.apply(StatusEffect::regen(60, 1));
+    assert_eq!(
+        player.tick_statuses(),
+        [
+            StatusEvent::Poisoned { damage: 5 },
+            StatusEvent::Regenerated { healed: 55 },
+            StatusEvent::Expired(StatusKind::Regen),
+        ]
+    );
+    assert_eq!(player.health, 100);
+    assert_eq!(
+        player.tick_statuses(),
+        [
+            StatusEvent::Poisoned { damage: 5 },
+            StatusEvent::Expired(StatusKind::Poison),
+        ]
+    );
+    assert!(player.statuses.is_empty());
+    assert_eq!(player.tick_statuses(), []);
+}
+
+#[test]
+fn test_shield_absorbs_until_depleted() {
+    let mut player = Player {
+        health: 50,
+        ..Default::default()
+    };
+    player.statuses.apply(StatusEffect::shield(8, 3));
+    assert_eq!(player.take_damage(5), 0);
+    assert_eq!(
+        player.statuses.get(StatusKind::Shield),
+        Some(&StatusEffect::shield(3, 3))
+    );
+    assert_eq!(player.take_damage(5), 2);
+    assert_eq!(player.health, 48);
+    assert_eq!(player.statuses.get(StatusKind::Shield), None);
+}
+
+#[test]
+fn test_shield_absorbs_poison() {
+    let mut player = Player {
+        health: 50,
+        ..Default::default()
+    };
+    player.statuses.apply(StatusEffect::poison(4, 3));
+    player.statuses.apply(StatusEffect::shield(6, 3));
+    assert_eq!(
+        player.tick_statuses(),
+        [StatusEvent::Poisoned { damage: 0 }]
+    );
+    assert_eq!(
+        player.tick_statuses(),
+        [StatusEvent::Poisoned { damage: 2 }]
+    );
+    assert_eq!(player.statuses.get(StatusKind::Shield), None);
+    assert_eq!(
+        player.tick_statuses(),
+        [
+            StatusEvent::Poisoned { damage: 4 },
+            StatusEvent::Expired(StatusKind::Poison)
+        ]
+    );
+    assert_eq!(player.health, 44);
+}
+
+#[test]
+fn test_shield_absorbs_health_paid_for_spells() {
+    let mut player = Player {
+        health: 20,
+        mana: None,
+        level: 6,
+        ..Default::default()
+    };
+    player.statuses.apply(StatusEffect::shield(25, 1));
+    assert_eq!(player.cast_spell(30), 0);
+    assert_eq!(player.health, 15);
+    assert_eq!(
+        Spell::arcane_bolt(10).cast(&mut player),
+        CastResult::PaidInHealth { health_lost: 10 }
+    );
+}
+
+#[test]
+fn test_revive_clears_statuses() {
+    let mut player = Player {
+        health: 0,
+        ..Default::default()
+    };
+    player.statuses.apply(StatusEffect::poison(5, 2));
+    assert!(player.revive().unwrap().statuses.is_empty());
+}
+
+#[test]
+fn test_spells_apply_statuses() {
+    let mut caster = Player {
+        health: 50,
+        mana: Some(100),
+        level: 10,
+        ..Default::default()
+    };
+    caster.spellbook.learn(Spell::new(
+        "venom",
+        10,
+        SpellEffect::Status(StatusEffect::poison(4, 3)),
+    ));
+    caster.spellbook.learn(Spell::new(
+        "barrier",
+        10,
+        SpellEffect::Status(StatusEffect::shield(20, 2)),
+    ));
+    assert_eq!(
+        caster.cast("barrier"),
+        CastResult::Success {
+            target: Target::Caster,
+            effects: vec![Effect::Status(StatusEffect::shield(20, 2))]
+        }
+    );
+    assert!(matches!(
+        caster.cast("venom"),
+        CastResult::Success {
+            target: Target::Opponent,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_battle_poison_and_stun() {
+    let mut caster = Player {
+        health: 50,
+        mana: Some(100),
+        level: 10,
+        ..Default::default()
+    };
+    caster.spellbook.learn(
+        Spell::new("venom", 10, SpellEffect::Status(StatusEffect::poison(4, 3)))
+            .with_effect(SpellEffect::Status(StatusEffect::stun(1))),
+    );
+    let mut battle = Battle::new(caster, Player::default(), 5);
+
+    battle.take_turn(Action::Cast("venom".to_string()));
+    battle.take_turn(Action::Attack);
+    assert_eq!(battle.attacker().health, 50);
+    assert_eq!(battle.defender().health, 96);
+    assert_eq!(
+        battle.log()[battle.log().len() - 4..],
+        [
+            Event::Turn {
+                number: 2,
+                side: Side::Defender
+            },
+            Event::Status {
+                side: Side::Defender,
+                event: StatusEvent::Poisoned { damage: 4 }
+            },
+            Event::Status {
+                side: Side::Defender,
+                event: StatusEvent::Stunned
+            },
+            Event::Status {
+                side: Side::Defender,
+                event: StatusEvent::Expired(StatusKind::Stun)
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_poison_can_win_a_battle() {
+    let mut victim = Player {
+        health: 3,
+        ..Default::default()
+    };
+    victim.statuses.apply(StatusEffect::poison(5, 1));
+    let mut battle = Battle::new(Player::default(), victim, 5);
+    battle.take_turn(Action::Defend);
+    assert_eq!(
+        battle.take_turn(Action::Attack),
+        Some(BattleOutcome::Victory(Side::Attacker))
+    );
+    assert_eq!(battle.attacker().health, 100);
+}
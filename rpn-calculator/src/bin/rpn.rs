@@ -0,0 +1,33 @@
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+use rpn_calculator::{BigInt, BigRational, Number, Session};
+
+const USAGE: &str = "usage: rpn [--int | --i64 | --float | --big | --rational]";
+
+fn run<T: Number>() -> io::Result<()> {
+    let mut session = Session::<T>::new();
+    for line in io::stdin().lock().lines() {
+        match session.eval_line(&line?) {
+            Ok(output) => println!("{}", output),
+            Err(err) => eprintln!("error: {}", err),
+        }
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let backend = env::args().nth(1);
+    match backend.as_deref() {
+        None | Some("--int") => run::<i32>(),
+        Some("--i64") => run::<i64>(),
+        Some("--float") => run::<f64>(),
+        Some("--big") => run::<BigInt>(),
+        Some("--rational") => run::<BigRational>(),
+        Some(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
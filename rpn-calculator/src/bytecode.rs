@@ -0,0 +1,135 @@
+use crate::{CalculatorInput, Number, RpnError};
+
+const PUSH: u8 = 0;
+
+fn opcode<T>(input: &CalculatorInput<T>) -> u8 {
+    match input {
+        CalculatorInput::Value(_) => PUSH,
+        CalculatorInput::Add => 1,
+        CalculatorInput::Subtract => 2,
+        CalculatorInput::Multiply => 3,
+        CalculatorInput::Divide => 4,
+        CalculatorInput::Modulo => 5,
+        CalculatorInput::Power => 6,
+        CalculatorInput::Negate => 7,
+        CalculatorInput::Abs => 8,
+        CalculatorInput::Min => 9,
+        CalculatorInput::Max => 10,
+        CalculatorInput::Dup => 11,
+        CalculatorInput::Drop => 12,
+        CalculatorInput::Swap => 13,
+        CalculatorInput::Over => 14,
+        CalculatorInput::Rot => 15,
+    }
+}
+
+/// A validated RPN program, ready to [`run`].
+///
+/// Each token becomes one instruction: a single opcode byte, followed by a
+/// little-endian `u32` constant index for pushes. Since [`compile`] has
+/// already checked the stack depth at every instruction, running it can only
+/// fail on arithmetic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program<T = i32> {
+    code: Vec<u8>,
+    constants: Vec<T>,
+    max_depth: usize,
+}
+
+impl<T> Program<T> {
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    pub fn constants(&self) -> &[T] {
+        &self.constants
+    }
+
+    /// The largest number of values on the stack at any point of the program.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+/// Checks the stack depth of `inputs` without evaluating anything and
+/// compiles them to a [`Program`].
+///
+/// Fails with the same underflow and final-depth errors [`crate::try_evaluate`] would.
+pub fn compile<T: Number>(inputs: &[CalculatorInput<T>]) -> Result<Program<T>, RpnError> {
+    let mut code = Vec::with_capacity(inputs.len());
+    let mut constants = vec![];
+    let mut depth = 0;
+    let mut max_depth = 0;
+    for (index, input) in inputs.iter().enumerate() {
+        if depth < input.arity() {
+            return Err(RpnError::StackUnderflow { index });
+        }
+        depth = depth - input.arity() + input.outputs();
+        max_depth = max_depth.max(depth);
+        code.push(opcode(input));
+        if let CalculatorInput::Value(value) = input {
+            code.extend((constants.len() as u32).to_le_bytes());
+            constants.push(value.clone());
+        }
+    }
+    match depth {
+        0 => Err(RpnError::EmptyStack),
+        1 => Ok(Program {
+            code,
+            constants,
+            max_depth,
+        }),
+        remaining => Err(RpnError::TooManyValues { remaining }),
+    }
+}
+
+/// Runs a compiled program. Error indices refer to the tokens it was compiled from.
+///
+/// Opcodes are dispatched directly and, since [`compile`] has already proved
+/// the stack depth at every instruction, without any underflow checks.
+pub fn run<T: Number>(program: &Program<T>) -> Result<T, RpnError> {
+    let mut stack = Vec::with_capacity(program.max_depth);
+    let mut pc = 0;
+    let mut index = 0;
+    while pc < program.code.len() {
+        let opcode = program.code[pc];
+        pc += 1;
+        let top = stack.len().wrapping_sub(1);
+        match opcode {
+            PUSH => {
+                let constant: [u8; 4] = program.code[pc..pc + 4].try_into().unwrap();
+                pc += 4;
+                stack.push(program.constants[u32::from_le_bytes(constant) as usize].clone());
+            }
+            7 => stack[top] = stack[top].try_neg().map_err(|err| err.at(index))?,
+            8 => stack[top] = stack[top].try_abs().map_err(|err| err.at(index))?,
+            11 => stack.push(stack[top].clone()),
+            12 => {
+                stack.pop();
+            }
+            13 => stack.swap(top, top - 1),
+            14 => stack.push(stack[top - 1].clone()),
+            15 => stack[top - 2..].rotate_left(1),
+            _ => {
+                let (op2, op1) = (&stack[top - 1], &stack[top]);
+                let res = match opcode {
+                    1 => op2.try_add(op1),
+                    2 => op2.try_sub(op1),
+                    3 => op2.try_mul(op1),
+                    4 => op2.try_div(op1),
+                    5 => op2.try_rem(op1),
+                    6 => op2.try_pow(op1),
+                    9 if op1 < op2 => Ok(op1.clone()),
+                    10 if op1 > op2 => Ok(op1.clone()),
+                    9 | 10 => Ok(op2.clone()),
+                    _ => unreachable!("invalid opcode {}", opcode),
+                }
+                .map_err(|err| err.at(index))?;
+                stack.pop();
+                stack[top - 1] = res;
+            }
+        }
+        index += 1;
+    }
+    Ok(stack.pop().expect("compile checked the final stack depth"))
+}
@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// Why an RPN expression could not be evaluated.
+///
+/// Indices refer to the position of the offending token in the input slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpnError {
+    /// An operator at `index` found fewer operands on the stack than it needs.
+    StackUnderflow { index: usize },
+    /// Evaluation finished with more than one value on the stack.
+    TooManyValues { remaining: usize },
+    /// Evaluation finished with nothing on the stack.
+    EmptyStack,
+    /// The operator at `index` tried to divide by zero.
+    DivisionByZero { index: usize },
+    /// The operator at `index` produced a result that doesn't fit the number type.
+    Overflow { index: usize },
+    /// The operator at `index` was asked to raise to a power the number type can't handle.
+    InvalidExponent { index: usize },
+}
+
+impl fmt::Display for RpnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RpnError::StackUnderflow { index } => {
+                write!(f, "stack underflow at token {}", index)
+            }
+            RpnError::TooManyValues { remaining } => {
+                write!(f, "{} values left on the stack, expected 1", remaining)
+            }
+            RpnError::EmptyStack => write!(f, "no value left on the stack"),
+            RpnError::DivisionByZero { index } => {
+                write!(f, "division by zero at token {}", index)
+            }
+            RpnError::Overflow { index } => write!(f, "arithmetic overflow at token {}", index),
+            RpnError::InvalidExponent { index } => write!(f, "invalid exponent at token {}", index),
+        }
+    }
+}
+
+impl std::error::Error for RpnError {}
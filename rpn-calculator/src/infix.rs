@@ -0,0 +1,376 @@
+use std::ops::Range;
+
+use crate::{CalculatorInput, Number, ParseError, ParseErrorKind, RpnError};
+
+enum Token<'a, T> {
+    Word(&'a str),
+    Operator(CalculatorInput<T>),
+    Minus,
+    Open,
+    Close,
+    Comma,
+}
+
+fn is_symbol(c: char) -> bool {
+    matches!(c, '+' | '-' | '*' | '/' | '%' | '^' | '(' | ')' | ',')
+}
+
+fn lex<T>(text: &str) -> Vec<(Range<usize>, Token<'_, T>)> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' => Token::Operator(CalculatorInput::Add),
+            '-' => Token::Minus,
+            '*' => Token::Operator(CalculatorInput::Multiply),
+            '/' => Token::Operator(CalculatorInput::Divide),
+            '%' => Token::Operator(CalculatorInput::Modulo),
+            '^' => Token::Operator(CalculatorInput::Power),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || is_symbol(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push((start..end, Token::Word(&text[start..end])));
+                continue;
+            }
+        };
+        tokens.push((start..start + c.len_utf8(), token));
+    }
+    tokens
+}
+
+fn precedence<T>(input: &CalculatorInput<T>) -> u8 {
+    match input {
+        CalculatorInput::Add | CalculatorInput::Subtract => 1,
+        CalculatorInput::Multiply | CalculatorInput::Divide | CalculatorInput::Modulo => 2,
+        CalculatorInput::Negate => 3,
+        CalculatorInput::Power => 4,
+        _ => u8::MAX,
+    }
+}
+
+fn is_right_associative<T>(input: &CalculatorInput<T>) -> bool {
+    matches!(input, CalculatorInput::Power)
+}
+
+fn function<T>(name: &str) -> Option<CalculatorInput<T>> {
+    match name {
+        "abs" => Some(CalculatorInput::Abs),
+        "min" => Some(CalculatorInput::Min),
+        "max" => Some(CalculatorInput::Max),
+        _ => None,
+    }
+}
+
+enum Pending<T> {
+    Operator(CalculatorInput<T>),
+    Open {
+        span: Range<usize>,
+        function: Option<CalculatorInput<T>>,
+        arguments: usize,
+    },
+}
+
+fn emit<T: Number>(output: &mut Vec<CalculatorInput<T>>, input: CalculatorInput<T>) {
+    if let (CalculatorInput::Negate, Some(CalculatorInput::Value(value))) =
+        (&input, output.last_mut())
+    {
+        // a negated literal folds into a negative value
+        if let Ok(negated) = value.try_neg() {
+            *value = negated;
+            return;
+        }
+    }
+    output.push(input);
+}
+
+fn error(span: Range<usize>, kind: ParseErrorKind) -> ParseError {
+    ParseError { span, kind }
+}
+
+fn operand<T: Number>(span: Range<usize>, word: &str) -> Result<CalculatorInput<T>, ParseError> {
+    match T::parse_literal(word) {
+        Some(value) => Ok(CalculatorInput::Value(value)),
+        None => Err(error(span, ParseErrorKind::InvalidToken(word.to_string()))),
+    }
+}
+
+fn push_operator<T: Number>(
+    output: &mut Vec<CalculatorInput<T>>,
+    operators: &mut Vec<Pending<T>>,
+    input: CalculatorInput<T>,
+) {
+    while let Some(Pending::Operator(top)) = operators.last() {
+        let binds_tighter = match is_right_associative(&input) {
+            true => precedence(top) > precedence(&input),
+            false => precedence(top) >= precedence(&input),
+        };
+        if !binds_tighter {
+            break;
+        }
+        if let Some(Pending::Operator(top)) = operators.pop() {
+            emit(output, top);
+        }
+    }
+    operators.push(Pending::Operator(input));
+}
+
+/// Pops operators up to the innermost open parenthesis and returns it, with
+/// the argument that was just closed counted.
+fn close_group<T: Number>(
+    output: &mut Vec<CalculatorInput<T>>,
+    operators: &mut Vec<Pending<T>>,
+    span: Range<usize>,
+) -> Result<Pending<T>, ParseError> {
+    loop {
+        match operators.pop() {
+            Some(Pending::Operator(input)) => emit(output, input),
+            Some(Pending::Open {
+                span,
+                function,
+                arguments,
+            }) => {
+                return Ok(Pending::Open {
+                    span,
+                    function,
+                    arguments: arguments + 1,
+                })
+            }
+            None => return Err(error(span, ParseErrorKind::MismatchedParenthesis)),
+        }
+    }
+}
+
+/// Converts infix text such as `"(3 + 4) * 2"` to RPN using the shunting-yard
+/// algorithm.
+///
+/// From loosest to tightest binding the operators are `+ -`, `* / %`, unary
+/// `-` and `^`. All binary operators associate to the left except `^`.
+/// `abs(x)`, `min(a, b)` and `max(a, b)` are available as functions.
+pub fn from_infix(text: &str) -> Result<Vec<CalculatorInput>, ParseError> {
+    from_infix_as(text)
+}
+
+/// Like [`from_infix`], but for any [`Number`] backend.
+pub fn from_infix_as<T: Number>(text: &str) -> Result<Vec<CalculatorInput<T>>, ParseError> {
+    let mut output = vec![];
+    let mut operators: Vec<Pending<T>> = vec![];
+    let mut expect_operand = true;
+    let mut tokens = lex(text).into_iter();
+    while let Some((span, token)) = tokens.next() {
+        match (token, expect_operand) {
+            (Token::Word(word), true) => match function(word) {
+                Some(input) => match tokens.next() {
+                    Some((span, Token::Open)) => operators.push(Pending::Open {
+                        span,
+                        function: Some(input),
+                        arguments: 0,
+                    }),
+                    _ => return Err(error(span, ParseErrorKind::InvalidToken(word.to_string()))),
+                },
+                None => {
+                    output.push(operand(span, word)?);
+                    expect_operand = false;
+                }
+            },
+            (Token::Open, true) => operators.push(Pending::Open {
+                span,
+                function: None,
+                arguments: 0,
+            }),
+            (Token::Minus, true) => {
+                // `-2147483648` only fits as a literal, but `-2 ^ 2` is still `-(2 ^ 2)`
+                let literal = match tokens.as_slice() {
+                    [(_, Token::Word(word)), rest @ ..]
+                        if !matches!(
+                            rest.first(),
+                            Some((_, Token::Operator(CalculatorInput::Power)))
+                        ) =>
+                    {
+                        T::parse_literal(&format!("-{}", word))
+                    }
+                    _ => None,
+                };
+                match literal {
+                    Some(value) => {
+                        tokens.next();
+                        output.push(CalculatorInput::Value(value));
+                        expect_operand = false;
+                    }
+                    None => operators.push(Pending::Operator(CalculatorInput::Negate)),
+                }
+            }
+            (Token::Word(_) | Token::Open, false) => {
+                return Err(error(span, ParseErrorKind::ExpectedOperator))
+            }
+            (Token::Operator(_) | Token::Close | Token::Comma, true) => {
+                return Err(error(span, ParseErrorKind::ExpectedOperand))
+            }
+            (Token::Comma, false) => {
+                let in_group = operators
+                    .iter()
+                    .any(|pending| matches!(pending, Pending::Open { .. }));
+                if !in_group {
+                    return Err(error(span, ParseErrorKind::InvalidToken(",".to_string())));
+                }
+                match close_group(&mut output, &mut operators, span.clone())? {
+                    Pending::Open {
+                        function: Some(input),
+                        arguments,
+                        ..
+                    } if arguments >= input.arity() => {
+                        return Err(error(span, ParseErrorKind::ArgumentCount(input.arity())))
+                    }
+                    Pending::Open { function: None, .. } => {
+                        return Err(error(span, ParseErrorKind::InvalidToken(",".to_string())))
+                    }
+                    group => operators.push(group),
+                }
+                expect_operand = true;
+            }
+            (Token::Close, false) => {
+                if let Pending::Open {
+                    function: Some(input),
+                    arguments,
+                    ..
+                } = close_group(&mut output, &mut operators, span.clone())?
+                {
+                    if arguments != input.arity() {
+                        return Err(error(span, ParseErrorKind::ArgumentCount(input.arity())));
+                    }
+                    output.push(input);
+                }
+            }
+            (Token::Operator(input), false) => {
+                push_operator(&mut output, &mut operators, input);
+                expect_operand = true;
+            }
+            (Token::Minus, false) => {
+                push_operator(&mut output, &mut operators, CalculatorInput::Subtract);
+                expect_operand = true;
+            }
+        }
+    }
+    if expect_operand {
+        return Err(error(
+            text.len()..text.len(),
+            ParseErrorKind::ExpectedOperand,
+        ));
+    }
+    while let Some(pending) = operators.pop() {
+        match pending {
+            Pending::Operator(input) => emit(&mut output, input),
+            Pending::Open { span, .. } => {
+                return Err(error(span, ParseErrorKind::MismatchedParenthesis))
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// A rendered subexpression and the precedence of its outermost operator.
+#[derive(Clone)]
+struct Rendered {
+    text: String,
+    precedence: u8,
+}
+
+impl Rendered {
+    fn new<T>(text: String, operator: CalculatorInput<T>) -> Rendered {
+        Rendered {
+            text,
+            precedence: precedence(&operator),
+        }
+    }
+
+    fn wrapped(self, parenthesize: bool) -> String {
+        match parenthesize {
+            true => format!("({})", self.text),
+            false => self.text,
+        }
+    }
+}
+
+fn render<T: Number>(input: CalculatorInput<T>, operands: Vec<Rendered>) -> Rendered {
+    let mut operands = operands.into_iter();
+    let mut next = || operands.next().unwrap();
+    let prec = precedence(&input);
+    match input {
+        CalculatorInput::Negate => {
+            let op = next();
+            let parens = op.precedence <= prec;
+            Rendered::new(format!("-{}", op.wrapped(parens)), input)
+        }
+        CalculatorInput::Abs => Rendered::new(format!("abs({})", next().text), input),
+        CalculatorInput::Min | CalculatorInput::Max => {
+            let (left, right) = (next(), next());
+            Rendered::new(format!("{}({}, {})", input, left.text, right.text), input)
+        }
+        _ => {
+            let (left, right) = (next(), next());
+            let left_parens =
+                left.precedence < prec || (left.precedence == prec && is_right_associative(&input));
+            // `a + (b + c)` keeps its parentheses too: checked and float
+            // arithmetic don't regroup freely
+            let right_parens = right.precedence < prec
+                || (right.precedence == prec && !is_right_associative(&input));
+            Rendered::new(
+                format!(
+                    "{} {} {}",
+                    left.wrapped(left_parens),
+                    input,
+                    right.wrapped(right_parens)
+                ),
+                input,
+            )
+        }
+    }
+}
+
+/// Formats `inputs` as infix text, adding only the parentheses needed to
+/// keep the evaluation order.
+///
+/// Stack words are resolved while rendering, so `3 dup *` becomes `3 * 3`.
+pub fn to_infix<T: Number>(inputs: &[CalculatorInput<T>]) -> Result<String, RpnError> {
+    let mut stack: Vec<Rendered> = vec![];
+    for (index, input) in inputs.iter().enumerate() {
+        let len = stack.len();
+        if len < input.arity() {
+            return Err(RpnError::StackUnderflow { index });
+        }
+        match input {
+            CalculatorInput::Value(value) => {
+                let mut rendered = Rendered::new(value.to_string(), input.clone());
+                if value.is_negative() {
+                    rendered.precedence = precedence(&CalculatorInput::<T>::Negate);
+                }
+                stack.push(rendered);
+            }
+            CalculatorInput::Dup => stack.push(stack[len - 1].clone()),
+            CalculatorInput::Drop => {
+                stack.pop();
+            }
+            CalculatorInput::Swap => stack.swap(len - 1, len - 2),
+            CalculatorInput::Over => stack.push(stack[len - 2].clone()),
+            CalculatorInput::Rot => stack[len - 3..].rotate_left(1),
+            _ => {
+                let operands = stack.split_off(len - input.arity());
+                stack.push(render(input.clone(), operands));
+            }
+        }
+    }
+    match stack.len() {
+        0 => Err(RpnError::EmptyStack),
+        1 => Ok(stack.pop().unwrap().text),
+        remaining => Err(RpnError::TooManyValues { remaining }),
+    }
+}
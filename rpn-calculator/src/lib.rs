@@ -1,50 +1,174 @@
-use std::vec;
+pub mod bytecode;
+pub mod error;
+pub mod infix;
+pub mod number;
+pub mod parse;
+pub mod repl;
+pub mod trace;
+pub mod words;
 
-#[derive(Debug, Clone, Copy)]
-pub enum CalculatorInput {
+pub use bytecode::{compile, run, Program};
+pub use error::RpnError;
+pub use infix::{from_infix, from_infix_as, to_infix};
+pub use num_bigint::BigInt;
+pub use num_rational::BigRational;
+pub use number::{ArithmeticError, Number};
+pub use parse::{parse, parse_as, to_rpn_string, ParseError, ParseErrorKind};
+pub use repl::{ReplError, Session};
+pub use trace::{evaluate_traced, Trace, TraceStep};
+pub use words::{Interpreter, ScriptError};
+
+/// A token of an RPN expression over numbers of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalculatorInput<T = i32> {
     Add,
     Subtract,
     Multiply,
     Divide,
-    Value(i32),
-}
-
-fn pop_operands_of_stack(stack: &mut Vec<CalculatorInput>) -> Option<(i32, i32)> {
-    let operands = (stack.pop(), stack.pop());
-    match operands {
-        (Some(CalculatorInput::Value(op1)), Some(CalculatorInput::Value(op2))) => Some((op1, op2)),
-        _ => None,
-    }
-}
-
-pub fn evaluate(inputs: &[CalculatorInput]) -> Option<i32> {
-    let mut stack: Vec<CalculatorInput> = vec![];
-    for input in inputs {
-        match input {
-            CalculatorInput::Value(_) => {
-                stack.append(&mut vec![*input]);
-            }
-            _ => {
-                let res: i32;
-                match pop_operands_of_stack(&mut stack) {
-                    Some((op1, op2)) => match input {
-                        CalculatorInput::Add => res = op2 + op1,
-                        CalculatorInput::Subtract => res = op2 - op1,
-                        CalculatorInput::Multiply => res = op2 * op1,
-                        CalculatorInput::Divide => res = op2 / op1,
-                        _ => return None,
-                    },
-                    None => return None,
-                }
-                stack.append(&mut vec![CalculatorInput::Value(res)])
-            }
+    Modulo,
+    Power,
+    Negate,
+    Abs,
+    Min,
+    Max,
+    Dup,
+    Drop,
+    Swap,
+    Over,
+    Rot,
+    Value(T),
+}
+
+impl<T> CalculatorInput<T> {
+    /// How many values this input takes off the stack.
+    pub fn arity(&self) -> usize {
+        match self {
+            CalculatorInput::Value(_) => 0,
+            CalculatorInput::Negate
+            | CalculatorInput::Abs
+            | CalculatorInput::Dup
+            | CalculatorInput::Drop => 1,
+            CalculatorInput::Rot => 3,
+            _ => 2,
         }
     }
-    if stack.len() == 1 {
-        match stack.pop() {
-            Some(CalculatorInput::Value(result)) => return Some(result),
-            _ => return None,
+
+    /// How many values this input leaves on the stack in place of the ones it took.
+    pub fn outputs(&self) -> usize {
+        match self {
+            CalculatorInput::Drop => 0,
+            CalculatorInput::Dup | CalculatorInput::Swap => 2,
+            CalculatorInput::Over | CalculatorInput::Rot => 3,
+            _ => 1,
+        }
+    }
+}
+
+fn unary<T: Number>(input: &CalculatorInput<T>, op: &T) -> Result<T, ArithmeticError> {
+    match input {
+        CalculatorInput::Negate => op.try_neg(),
+        CalculatorInput::Abs => op.try_abs(),
+        _ => unreachable!("{:?} is not a unary operator", input),
+    }
+}
+
+fn binary<T: Number>(input: &CalculatorInput<T>, op2: &T, op1: &T) -> Result<T, ArithmeticError> {
+    match input {
+        CalculatorInput::Add => op2.try_add(op1),
+        CalculatorInput::Subtract => op2.try_sub(op1),
+        CalculatorInput::Multiply => op2.try_mul(op1),
+        CalculatorInput::Divide => op2.try_div(op1),
+        CalculatorInput::Modulo => op2.try_rem(op1),
+        CalculatorInput::Power => op2.try_pow(op1),
+        CalculatorInput::Min if op1 < op2 => Ok(op1.clone()),
+        CalculatorInput::Max if op1 > op2 => Ok(op1.clone()),
+        CalculatorInput::Min | CalculatorInput::Max => Ok(op2.clone()),
+        _ => unreachable!("{:?} is not a binary operator", input),
+    }
+}
+
+/// Applies a single input to `stack`, leaving the stack untouched on error.
+pub(crate) fn apply<T: Number>(
+    stack: &mut Vec<T>,
+    input: &CalculatorInput<T>,
+    index: usize,
+) -> Result<(), RpnError> {
+    let len = stack.len();
+    if len < input.arity() {
+        return Err(RpnError::StackUnderflow { index });
+    }
+    match input {
+        CalculatorInput::Value(value) => stack.push(value.clone()),
+        CalculatorInput::Dup => stack.push(stack[len - 1].clone()),
+        CalculatorInput::Drop => {
+            stack.pop();
+        }
+        CalculatorInput::Swap => stack.swap(len - 1, len - 2),
+        CalculatorInput::Over => stack.push(stack[len - 2].clone()),
+        CalculatorInput::Rot => stack[len - 3..].rotate_left(1),
+        CalculatorInput::Negate | CalculatorInput::Abs => {
+            stack[len - 1] = unary(input, &stack[len - 1]).map_err(|err| err.at(index))?;
+        }
+        _ => {
+            let res =
+                binary(input, &stack[len - 2], &stack[len - 1]).map_err(|err| err.at(index))?;
+            stack.pop();
+            stack[len - 2] = res;
         }
     }
-    return None;
+    Ok(())
+}
+
+/// Watches [`execute`] apply inputs one at a time.
+pub(crate) trait Observer<T> {
+    fn before(&mut self, stack: &[T]);
+    fn after(&mut self, index: usize, input: &CalculatorInput<T>, stack: &[T]);
+}
+
+impl<T> Observer<T> for () {
+    fn before(&mut self, _stack: &[T]) {}
+    fn after(&mut self, _index: usize, _input: &CalculatorInput<T>, _stack: &[T]) {}
+}
+
+pub(crate) fn execute_observed<T: Number>(
+    stack: &mut Vec<T>,
+    inputs: &[CalculatorInput<T>],
+    observer: &mut impl Observer<T>,
+) -> Result<(), RpnError> {
+    for (index, input) in inputs.iter().enumerate() {
+        observer.before(stack);
+        apply(stack, input, index)?;
+        observer.after(index, input, stack);
+    }
+    Ok(())
+}
+
+/// Runs `inputs` on top of an existing `stack`.
+///
+/// On error the stack holds the state left by the tokens before the failing one.
+pub fn execute<T: Number>(
+    stack: &mut Vec<T>,
+    inputs: &[CalculatorInput<T>],
+) -> Result<(), RpnError> {
+    execute_observed(stack, inputs, &mut ())
+}
+
+/// Takes the single value a finished evaluation should have left.
+pub(crate) fn result<T>(mut stack: Vec<T>) -> Result<T, RpnError> {
+    match stack.len() {
+        0 => Err(RpnError::EmptyStack),
+        1 => Ok(stack.pop().unwrap()),
+        remaining => Err(RpnError::TooManyValues { remaining }),
+    }
+}
+
+/// Evaluates `inputs`, reporting which token broke the expression on failure.
+pub fn try_evaluate<T: Number>(inputs: &[CalculatorInput<T>]) -> Result<T, RpnError> {
+    let mut stack: Vec<T> = vec![];
+    execute(&mut stack, inputs)?;
+    result(stack)
+}
+
+pub fn evaluate<T: Number>(inputs: &[CalculatorInput<T>]) -> Option<T> {
+    try_evaluate(inputs).ok()
 }
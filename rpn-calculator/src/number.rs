@@ -0,0 +1,307 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::RpnError;
+
+/// Why a single arithmetic operation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    DivisionByZero,
+    Overflow,
+    InvalidExponent,
+}
+
+impl ArithmeticError {
+    /// Attaches the index of the token that failed.
+    pub fn at(self, index: usize) -> RpnError {
+        match self {
+            ArithmeticError::DivisionByZero => RpnError::DivisionByZero { index },
+            ArithmeticError::Overflow => RpnError::Overflow { index },
+            ArithmeticError::InvalidExponent => RpnError::InvalidExponent { index },
+        }
+    }
+}
+
+/// A number type the calculator can run on.
+///
+/// Every operation reports failure instead of panicking or wrapping, and each
+/// backend decides what division means for it:
+///
+/// - `i32`, `i64` and [`BigInt`] truncate towards zero,
+/// - `f64` divides as floating point, rejecting non-finite results,
+/// - [`BigRational`] divides exactly.
+pub trait Number: Clone + PartialOrd + fmt::Debug + fmt::Display {
+    /// Reads a number token, returning `None` if `text` isn't one.
+    fn parse_literal(text: &str) -> Option<Self>;
+    fn is_negative(&self) -> bool;
+    fn try_add(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+    fn try_sub(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+    fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+    fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+    fn try_rem(&self, rhs: &Self) -> Result<Self, ArithmeticError>;
+    fn try_pow(&self, exponent: &Self) -> Result<Self, ArithmeticError>;
+    fn try_neg(&self) -> Result<Self, ArithmeticError>;
+    fn try_abs(&self) -> Result<Self, ArithmeticError>;
+}
+
+macro_rules! impl_checked_number {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn parse_literal(text: &str) -> Option<Self> {
+                text.parse().ok()
+            }
+
+            fn is_negative(&self) -> bool {
+                *self < 0
+            }
+
+            fn try_add(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+                self.checked_add(*rhs).ok_or(ArithmeticError::Overflow)
+            }
+
+            fn try_sub(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+                self.checked_sub(*rhs).ok_or(ArithmeticError::Overflow)
+            }
+
+            fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+                self.checked_mul(*rhs).ok_or(ArithmeticError::Overflow)
+            }
+
+            fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+                match rhs {
+                    0 => Err(ArithmeticError::DivisionByZero),
+                    _ => self.checked_div(*rhs).ok_or(ArithmeticError::Overflow),
+                }
+            }
+
+            fn try_rem(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+                match rhs {
+                    0 => Err(ArithmeticError::DivisionByZero),
+                    _ => self.checked_rem(*rhs).ok_or(ArithmeticError::Overflow),
+                }
+            }
+
+            fn try_pow(&self, exponent: &Self) -> Result<Self, ArithmeticError> {
+                let exponent =
+                    u32::try_from(*exponent).map_err(|_| ArithmeticError::InvalidExponent)?;
+                self.checked_pow(exponent).ok_or(ArithmeticError::Overflow)
+            }
+
+            fn try_neg(&self) -> Result<Self, ArithmeticError> {
+                self.checked_neg().ok_or(ArithmeticError::Overflow)
+            }
+
+            fn try_abs(&self) -> Result<Self, ArithmeticError> {
+                self.checked_abs().ok_or(ArithmeticError::Overflow)
+            }
+        }
+    )*};
+}
+
+impl_checked_number!(i32, i64);
+
+fn finite(value: f64) -> Result<f64, ArithmeticError> {
+    match value.is_finite() {
+        true => Ok(value),
+        false => Err(ArithmeticError::Overflow),
+    }
+}
+
+impl Number for f64 {
+    fn parse_literal(text: &str) -> Option<Self> {
+        // `inf` and `NaN` would otherwise parse as numbers
+        text.parse().ok().filter(|value: &f64| value.is_finite())
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < 0.0
+    }
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        finite(self + rhs)
+    }
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        finite(self - rhs)
+    }
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        finite(self * rhs)
+    }
+
+    fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match *rhs == 0.0 {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => finite(self / rhs),
+        }
+    }
+
+    fn try_rem(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match *rhs == 0.0 {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => finite(self % rhs),
+        }
+    }
+
+    fn try_pow(&self, exponent: &Self) -> Result<Self, ArithmeticError> {
+        match self.powf(*exponent) {
+            // e.g. a fractional power of a negative number
+            value if value.is_nan() => Err(ArithmeticError::InvalidExponent),
+            value => finite(value),
+        }
+    }
+
+    fn try_neg(&self) -> Result<Self, ArithmeticError> {
+        Ok(-self)
+    }
+
+    fn try_abs(&self) -> Result<Self, ArithmeticError> {
+        Ok(self.abs())
+    }
+}
+
+impl Number for BigInt {
+    fn parse_literal(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(self)
+    }
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self + rhs)
+    }
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self - rhs)
+    }
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self * rhs)
+    }
+
+    fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match rhs.is_zero() {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => Ok(self / rhs),
+        }
+    }
+
+    fn try_rem(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match rhs.is_zero() {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => Ok(self % rhs),
+        }
+    }
+
+    fn try_pow(&self, exponent: &Self) -> Result<Self, ArithmeticError> {
+        let exponent = exponent.to_u32().ok_or(ArithmeticError::InvalidExponent)?;
+        check_power_size(self, exponent.into())?;
+        Ok(self.pow(exponent))
+    }
+
+    fn try_neg(&self) -> Result<Self, ArithmeticError> {
+        Ok(-self)
+    }
+
+    fn try_abs(&self) -> Result<Self, ArithmeticError> {
+        Ok(self.abs())
+    }
+}
+
+/// The largest power [`BigInt`] and [`BigRational`] will compute, in bits;
+/// anything bigger is an [`ArithmeticError::Overflow`] rather than a huge allocation.
+pub const MAX_POWER_BITS: u64 = 1 << 20;
+
+/// Fails if `base^exponent` needs more than [`MAX_POWER_BITS`], going by a
+/// lower bound so that 0, 1 and -1 are never refused.
+fn check_power_size(base: &BigInt, exponent: u64) -> Result<(), ArithmeticError> {
+    match base.bits().saturating_sub(1).saturating_mul(exponent) > MAX_POWER_BITS {
+        true => Err(ArithmeticError::Overflow),
+        false => Ok(()),
+    }
+}
+
+/// Reads `"3"`, `"-3/4"` or `"0.75"` as an exact rational.
+fn parse_rational(text: &str) -> Option<BigRational> {
+    if let Some((numerator, denominator)) = text.split_once('/') {
+        let numerator: BigInt = numerator.parse().ok()?;
+        let denominator: BigInt = denominator.parse().ok()?;
+        return match denominator.is_zero() {
+            true => None,
+            false => Some(BigRational::new(numerator, denominator)),
+        };
+    }
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits: BigInt = format!("{}{}", whole, fraction).parse().ok()?;
+    let scale = BigInt::from(10).pow(fraction.len() as u32);
+    Some(BigRational::new(digits, scale))
+}
+
+impl Number for BigRational {
+    fn parse_literal(text: &str) -> Option<Self> {
+        parse_rational(text)
+    }
+
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(self)
+    }
+
+    fn try_add(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self + rhs)
+    }
+
+    fn try_sub(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self - rhs)
+    }
+
+    fn try_mul(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        Ok(self * rhs)
+    }
+
+    fn try_div(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match rhs.is_zero() {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => Ok(self / rhs),
+        }
+    }
+
+    fn try_rem(&self, rhs: &Self) -> Result<Self, ArithmeticError> {
+        match rhs.is_zero() {
+            true => Err(ArithmeticError::DivisionByZero),
+            false => Ok(self % rhs),
+        }
+    }
+
+    /// Only whole exponents keep the result rational.
+    fn try_pow(&self, exponent: &Self) -> Result<Self, ArithmeticError> {
+        if !exponent.is_integer() {
+            return Err(ArithmeticError::InvalidExponent);
+        }
+        let exponent = exponent
+            .to_integer()
+            .to_i32()
+            .ok_or(ArithmeticError::InvalidExponent)?;
+        if self.is_zero() && exponent < 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        check_power_size(self.numer(), exponent.unsigned_abs().into())?;
+        check_power_size(self.denom(), exponent.unsigned_abs().into())?;
+        Ok(num_traits::Pow::pow(self, exponent))
+    }
+
+    fn try_neg(&self) -> Result<Self, ArithmeticError> {
+        Ok(-self)
+    }
+
+    fn try_abs(&self) -> Result<Self, ArithmeticError> {
+        Ok(self.abs())
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::{CalculatorInput, Number};
+
+/// A token that could not be turned into a [`CalculatorInput`], or that
+/// doesn't fit where it appears in an infix expression.
+///
+/// `span` is the byte range of the token in the parsed text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub span: Range<usize>,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidToken(String),
+    MismatchedParenthesis,
+    /// A number or `(` was expected, but an operator, `)` or the end of input was found.
+    ExpectedOperand,
+    /// An operator or `)` was expected, but a number or `(` was found.
+    ExpectedOperator,
+    /// A function was called with the wrong number of arguments; it takes this many.
+    ArgumentCount(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::InvalidToken(token) => write!(f, "invalid token `{}`", token)?,
+            ParseErrorKind::MismatchedParenthesis => write!(f, "mismatched parenthesis")?,
+            ParseErrorKind::ExpectedOperand => write!(f, "expected a value")?,
+            ParseErrorKind::ExpectedOperator => write!(f, "expected an operator")?,
+            ParseErrorKind::ArgumentCount(expected) => {
+                write!(f, "expected {} function arguments", expected)?
+            }
+        }
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<T: Number> FromStr for CalculatorInput<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(CalculatorInput::Add),
+            "-" => Ok(CalculatorInput::Subtract),
+            "*" => Ok(CalculatorInput::Multiply),
+            "/" => Ok(CalculatorInput::Divide),
+            "%" => Ok(CalculatorInput::Modulo),
+            "^" => Ok(CalculatorInput::Power),
+            "neg" => Ok(CalculatorInput::Negate),
+            "abs" => Ok(CalculatorInput::Abs),
+            "min" => Ok(CalculatorInput::Min),
+            "max" => Ok(CalculatorInput::Max),
+            "dup" => Ok(CalculatorInput::Dup),
+            "drop" => Ok(CalculatorInput::Drop),
+            "swap" => Ok(CalculatorInput::Swap),
+            "over" => Ok(CalculatorInput::Over),
+            "rot" => Ok(CalculatorInput::Rot),
+            n => T::parse_literal(n)
+                .map(CalculatorInput::Value)
+                .ok_or_else(|| ParseError {
+                    span: 0..s.len(),
+                    kind: ParseErrorKind::InvalidToken(s.to_string()),
+                }),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CalculatorInput<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalculatorInput::Add => write!(f, "+"),
+            CalculatorInput::Subtract => write!(f, "-"),
+            CalculatorInput::Multiply => write!(f, "*"),
+            CalculatorInput::Divide => write!(f, "/"),
+            CalculatorInput::Modulo => write!(f, "%"),
+            CalculatorInput::Power => write!(f, "^"),
+            CalculatorInput::Negate => write!(f, "neg"),
+            CalculatorInput::Abs => write!(f, "abs"),
+            CalculatorInput::Min => write!(f, "min"),
+            CalculatorInput::Max => write!(f, "max"),
+            CalculatorInput::Dup => write!(f, "dup"),
+            CalculatorInput::Drop => write!(f, "drop"),
+            CalculatorInput::Swap => write!(f, "swap"),
+            CalculatorInput::Over => write!(f, "over"),
+            CalculatorInput::Rot => write!(f, "rot"),
+            CalculatorInput::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Splits `text` on whitespace, yielding every token with its byte span.
+pub(crate) fn tokenize(text: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    text.split_whitespace().map(move |token| {
+        let start = token.as_ptr() as usize - text.as_ptr() as usize;
+        (start..start + token.len(), token)
+    })
+}
+
+/// Parses whitespace separated RPN text such as `"3 4 + 2 *"`.
+pub fn parse(text: &str) -> Result<Vec<CalculatorInput>, ParseError> {
+    parse_as(text)
+}
+
+/// Like [`parse`], but for any [`Number`] backend, e.g. `parse_as::<f64>("1.5 2 *")`.
+pub fn parse_as<T: Number>(text: &str) -> Result<Vec<CalculatorInput<T>>, ParseError> {
+    tokenize(text)
+        .map(|(span, token)| {
+            token.parse().map_err(|err: ParseError| ParseError {
+                span,
+                kind: err.kind,
+            })
+        })
+        .collect()
+}
+
+/// Formats `inputs` as whitespace separated RPN text, the inverse of [`parse`].
+pub fn to_rpn_string<T: fmt::Display>(inputs: &[CalculatorInput<T>]) -> String {
+    inputs
+        .iter()
+        .map(|input| input.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
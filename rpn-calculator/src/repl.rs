@@ -0,0 +1,128 @@
+use std::fmt;
+
+use crate::{Interpreter, Number, RpnError, ScriptError};
+
+/// Why a REPL line was rejected. The stack is left as it was before the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplError {
+    Script(ScriptError),
+    Eval(RpnError),
+    UnknownCommand(String),
+    NothingToUndo,
+}
+
+impl fmt::Display for ReplError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplError::Script(err) => write!(f, "{}", err),
+            ReplError::Eval(err) => write!(f, "{}", err),
+            ReplError::UnknownCommand(command) => write!(f, "unknown command `{}`", command),
+            ReplError::NothingToUndo => write!(f, "nothing to undo"),
+        }
+    }
+}
+
+impl std::error::Error for ReplError {}
+
+impl From<ScriptError> for ReplError {
+    fn from(err: ScriptError) -> Self {
+        match err {
+            ScriptError::Eval(err) => ReplError::Eval(err),
+            err => ReplError::Script(err),
+        }
+    }
+}
+
+/// An interactive calculator whose stack survives from one line to the next.
+///
+/// Lines are run by an [`Interpreter`], so words and variables defined on
+/// one line can be used on the next. Besides that a line may be one of the
+/// meta-commands `.clear`, `.stack`, `.undo` and `.history`.
+#[derive(Debug, Clone)]
+pub struct Session<T = i32> {
+    stack: Vec<T>,
+    interpreter: Interpreter<T>,
+    undo: Vec<(Vec<T>, Interpreter<T>)>,
+    history: Vec<String>,
+}
+
+impl<T: Number> Default for Session<T> {
+    fn default() -> Self {
+        Session {
+            stack: vec![],
+            interpreter: Interpreter::new(),
+            undo: vec![],
+            history: vec![],
+        }
+    }
+}
+
+impl<T: Number> Session<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stack(&self) -> &[T] {
+        &self.stack
+    }
+
+    /// The lines that ran without error and haven't been undone, oldest
+    /// first. That includes `.clear` and lines that only define words or
+    /// variables.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Handles one line of input and returns the text to show for it.
+    pub fn eval_line(&mut self, line: &str) -> Result<String, ReplError> {
+        let line = line.trim();
+        match line {
+            ".clear" => {
+                self.undo
+                    .push((std::mem::take(&mut self.stack), self.interpreter.clone()));
+                self.history.push(line.to_string());
+            }
+            ".stack" => {}
+            ".undo" => {
+                (self.stack, self.interpreter) = self.undo.pop().ok_or(ReplError::NothingToUndo)?;
+                self.history.pop();
+            }
+            ".history" => {
+                return Ok(self
+                    .history
+                    .iter()
+                    .enumerate()
+                    .map(|(i, line)| format!("{:>4}  {}", i + 1, line))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            command if command.starts_with('.') => {
+                return Err(ReplError::UnknownCommand(command.to_string()))
+            }
+            "" => {}
+            _ => {
+                let mut stack = self.stack.clone();
+                let mut interpreter = self.interpreter.clone();
+                interpreter.eval_line(&mut stack, line)?;
+                self.undo.push((
+                    std::mem::replace(&mut self.stack, stack),
+                    std::mem::replace(&mut self.interpreter, interpreter),
+                ));
+                self.history.push(line.to_string());
+            }
+        }
+        Ok(self.render_stack())
+    }
+
+    fn render_stack(&self) -> String {
+        match self.stack.is_empty() {
+            true => "(empty)".to_string(),
+            false => self
+                .stack
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::{execute_observed, result, CalculatorInput, Number, Observer, RpnError};
+
+/// One token of a traced evaluation and the stack around it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(bound(serialize = "T: Serialize + fmt::Display"))]
+pub struct TraceStep<T = i32> {
+    pub index: usize,
+    #[serde(serialize_with = "serialize_display")]
+    pub token: CalculatorInput<T>,
+    pub before: Vec<T>,
+    pub after: Vec<T>,
+}
+
+fn serialize_display<S: Serializer>(
+    value: &impl fmt::Display,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Every step of an evaluation, up to the end or the token that failed.
+///
+/// `Display` renders it as a table. With `serde` it serializes to
+/// `{"steps": [...], "result": ..., "error": ...}`, where exactly one of
+/// `result` and `error` is `null`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace<T = i32> {
+    pub steps: Vec<TraceStep<T>>,
+    pub result: Result<T, RpnError>,
+}
+
+struct Tracer<T> {
+    before: Vec<T>,
+    steps: Vec<TraceStep<T>>,
+}
+
+impl<T: Clone> Observer<T> for Tracer<T> {
+    fn before(&mut self, stack: &[T]) {
+        self.before = stack.to_vec();
+    }
+
+    fn after(&mut self, index: usize, input: &CalculatorInput<T>, stack: &[T]) {
+        self.steps.push(TraceStep {
+            index,
+            token: input.clone(),
+            before: std::mem::take(&mut self.before),
+            after: stack.to_vec(),
+        });
+    }
+}
+
+/// Evaluates `inputs` like [`crate::try_evaluate`], recording the stack
+/// before and after every token.
+pub fn evaluate_traced<T: Number>(inputs: &[CalculatorInput<T>]) -> Trace<T> {
+    let mut tracer = Tracer {
+        before: vec![],
+        steps: vec![],
+    };
+    let mut stack = vec![];
+    let result = execute_observed(&mut stack, inputs, &mut tracer).and_then(|_| result(stack));
+    Trace {
+        steps: tracer.steps,
+        result,
+    }
+}
+
+fn render_stack<T: fmt::Display>(stack: &[T]) -> String {
+    stack
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl<T: fmt::Display> fmt::Display for Trace<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<[String; 4]> = self
+            .steps
+            .iter()
+            .map(|step| {
+                [
+                    step.index.to_string(),
+                    step.token.to_string(),
+                    render_stack(&step.before),
+                    render_stack(&step.after),
+                ]
+            })
+            .collect();
+        let header = ["#", "token", "before", "after"].map(String::from);
+        let mut widths = header.clone().map(|cell| cell.len());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in std::iter::once(&header).chain(&rows) {
+            let line = format!(
+                "{:>w0$} | {:<w1$} | {:<w2$} | {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            );
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        match &self.result {
+            Ok(value) => write!(f, "result: {}", value),
+            Err(err) => write!(f, "error: {}", err),
+        }
+    }
+}
+
+impl<T: Serialize + fmt::Display> Serialize for Trace<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Trace", 3)?;
+        state.serialize_field("steps", &self.steps)?;
+        state.serialize_field("result", &self.result.as_ref().ok())?;
+        state.serialize_field(
+            "error",
+            &self.result.as_ref().err().map(|err| err.to_string()),
+        )?;
+        state.end()
+    }
+}
@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::parse::tokenize;
+use crate::{apply, CalculatorInput, Number, RpnError};
+
+/// Why a script line could not be run.
+///
+/// Spans are byte ranges in the line that was passed in; an error inside a
+/// user word points at the token that called it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    Eval(RpnError),
+    UnknownWord {
+        name: String,
+        span: Range<usize>,
+    },
+    /// The name is a number, a built-in operator or otherwise reserved.
+    InvalidName {
+        name: String,
+        span: Range<usize>,
+    },
+    /// The name is already taken by a variable, or by a word when assigning.
+    NameInUse {
+        name: String,
+        span: Range<usize>,
+    },
+    UnterminatedDefinition {
+        span: Range<usize>,
+    },
+    UnexpectedToken {
+        token: String,
+        span: Range<usize>,
+    },
+    /// Calling `name` nested user words deeper than the interpreter allows.
+    DepthExceeded {
+        name: String,
+        span: Range<usize>,
+    },
+    /// The line ran more words than the interpreter allows; `span` is the
+    /// top-level token that was running.
+    StepsExceeded {
+        span: Range<usize>,
+    },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let span = match self {
+            ScriptError::Eval(err) => return write!(f, "{}", err),
+            ScriptError::UnknownWord { name, span } => {
+                write!(f, "unknown word `{}`", name)?;
+                span
+            }
+            ScriptError::InvalidName { name, span } => {
+                write!(f, "`{}` can't be used as a name", name)?;
+                span
+            }
+            ScriptError::NameInUse { name, span } => {
+                write!(f, "`{}` is already defined", name)?;
+                span
+            }
+            ScriptError::UnterminatedDefinition { span } => {
+                write!(f, "definition is missing its `;`")?;
+                span
+            }
+            ScriptError::UnexpectedToken { token, span } => {
+                write!(f, "unexpected `{}`", token)?;
+                span
+            }
+            ScriptError::DepthExceeded { name, span } => {
+                write!(f, "`{}` nests words too deeply", name)?;
+                span
+            }
+            ScriptError::StepsExceeded { span } => {
+                write!(f, "too many steps")?;
+                span
+            }
+        };
+        write!(f, " at {}..{}", span.start, span.end)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Word<T> {
+    Input(CalculatorInput<T>),
+    Store(String),
+    Call(String),
+}
+
+/// Runs RPN text extended with user-defined words and variables.
+///
+/// - `: square dup * ;` defines a word; its body is looked up when it runs,
+///   so words may refer to words defined later, or to themselves.
+/// - `=x` pops the top of the stack into the variable `x`, and `x` pushes it back.
+///
+/// A user word may be redefined, replacing the old body, but built-in
+/// operators and numbers can't be. A name is either a word or a variable, never both.
+#[derive(Debug, Clone)]
+pub struct Interpreter<T = i32> {
+    words: HashMap<String, Rc<[Word<T>]>>,
+    variables: HashMap<String, T>,
+    max_depth: usize,
+    max_steps: u64,
+}
+
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+pub const DEFAULT_MAX_STEPS: u64 = 1_000_000;
+
+impl<T: Number> Default for Interpreter<T> {
+    fn default() -> Self {
+        Interpreter {
+            words: HashMap::new(),
+            variables: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_steps: DEFAULT_MAX_STEPS,
+        }
+    }
+}
+
+impl<T: Number> Interpreter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how deeply user words may call each other.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Limits how many words, built-in or not, one [`Interpreter::eval_line`]
+    /// or [`Interpreter::expand`] may run. Shallow words can still do a lot
+    /// of work: each level of `: w1 w0 w0 ;` doubles it.
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn variable(&self, name: &str) -> Option<&T> {
+        self.variables.get(name)
+    }
+
+    /// The built-in inputs `name` would run right now, with variables
+    /// replaced by their current values.
+    ///
+    /// Fails for words that assign variables, since those only make sense
+    /// while running.
+    pub fn expand(&self, name: &str) -> Result<Vec<CalculatorInput<T>>, ScriptError> {
+        let mut inputs = vec![];
+        self.expand_into(&mut inputs, name, &(0..0), 0, &mut 0)?;
+        Ok(inputs)
+    }
+
+    fn expand_into(
+        &self,
+        inputs: &mut Vec<CalculatorInput<T>>,
+        name: &str,
+        span: &Range<usize>,
+        depth: usize,
+        steps: &mut u64,
+    ) -> Result<(), ScriptError> {
+        self.step(steps, span)?;
+        if let Some(value) = self.variables.get(name) {
+            inputs.push(CalculatorInput::Value(value.clone()));
+            return Ok(());
+        }
+        let body = self.body(name, span, depth)?;
+        for word in body.iter() {
+            match word {
+                Word::Input(input) => {
+                    self.step(steps, span)?;
+                    inputs.push(input.clone())
+                }
+                Word::Call(name) => self.expand_into(inputs, name, span, depth + 1, steps)?,
+                Word::Store(name) => {
+                    return Err(ScriptError::UnexpectedToken {
+                        token: format!("={}", name),
+                        span: span.clone(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts one more step against the budget of the current line.
+    fn step(&self, steps: &mut u64, span: &Range<usize>) -> Result<(), ScriptError> {
+        *steps += 1;
+        match *steps > self.max_steps {
+            true => Err(ScriptError::StepsExceeded { span: span.clone() }),
+            false => Ok(()),
+        }
+    }
+
+    fn body(
+        &self,
+        name: &str,
+        span: &Range<usize>,
+        depth: usize,
+    ) -> Result<Rc<[Word<T>]>, ScriptError> {
+        if depth >= self.max_depth {
+            return Err(ScriptError::DepthExceeded {
+                name: name.to_string(),
+                span: span.clone(),
+            });
+        }
+        self.words
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ScriptError::UnknownWord {
+                name: name.to_string(),
+                span: span.clone(),
+            })
+    }
+
+    /// Evaluates `text` on an empty stack, expecting exactly one value back.
+    pub fn evaluate(&mut self, text: &str) -> Result<T, ScriptError> {
+        let mut stack = vec![];
+        self.eval_line(&mut stack, text)?;
+        match stack.len() {
+            0 => Err(ScriptError::Eval(RpnError::EmptyStack)),
+            1 => Ok(stack.pop().unwrap()),
+            remaining => Err(ScriptError::Eval(RpnError::TooManyValues { remaining })),
+        }
+    }
+
+    /// Runs one line of text on top of `stack`, keeping any definitions it makes.
+    ///
+    /// Token indices in [`RpnError`]s count the tokens of `text`.
+    pub fn eval_line(&mut self, stack: &mut Vec<T>, text: &str) -> Result<(), ScriptError> {
+        let mut tokens = tokenize(text).enumerate();
+        let mut steps = 0;
+        while let Some((index, (span, token))) = tokens.next() {
+            match token {
+                ":" => {
+                    let (name_span, name) = match tokens.next() {
+                        Some((_, (span, name))) => (span, name),
+                        None => return Err(ScriptError::UnterminatedDefinition { span }),
+                    };
+                    self.check_name(name, &name_span)?;
+                    if self.variables.contains_key(name) {
+                        return Err(ScriptError::NameInUse {
+                            name: name.to_string(),
+                            span: name_span,
+                        });
+                    }
+                    let mut body = vec![];
+                    loop {
+                        match tokens.next() {
+                            Some((_, (_, ";"))) => break,
+                            Some((_, (span, ":"))) => {
+                                return Err(ScriptError::UnexpectedToken {
+                                    token: ":".to_string(),
+                                    span,
+                                })
+                            }
+                            Some((_, (span, token))) => body.push(self.compile(token, &span)?),
+                            None => return Err(ScriptError::UnterminatedDefinition { span }),
+                        }
+                    }
+                    self.words.insert(name.to_string(), body.into());
+                }
+                ";" => {
+                    return Err(ScriptError::UnexpectedToken {
+                        token: token.to_string(),
+                        span,
+                    })
+                }
+                _ => {
+                    let word = self.compile(token, &span)?;
+                    self.run(stack, &word, &span, index, 0, &mut steps)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_name(&self, name: &str, span: &Range<usize>) -> Result<(), ScriptError> {
+        let reserved = name.parse::<CalculatorInput<T>>().is_ok()
+            || matches!(name, ":" | ";")
+            || name.starts_with(['=', '.']);
+        match reserved {
+            true => Err(ScriptError::InvalidName {
+                name: name.to_string(),
+                span: span.clone(),
+            }),
+            false => Ok(()),
+        }
+    }
+
+    fn compile(&self, token: &str, span: &Range<usize>) -> Result<Word<T>, ScriptError> {
+        if let Ok(input) = token.parse() {
+            return Ok(Word::Input(input));
+        }
+        match token.strip_prefix('=') {
+            Some(name) => {
+                self.check_name(name, span)?;
+                Ok(Word::Store(name.to_string()))
+            }
+            None => Ok(Word::Call(token.to_string())),
+        }
+    }
+
+    fn run(
+        &mut self,
+        stack: &mut Vec<T>,
+        word: &Word<T>,
+        span: &Range<usize>,
+        index: usize,
+        depth: usize,
+        steps: &mut u64,
+    ) -> Result<(), ScriptError> {
+        self.step(steps, span)?;
+        match word {
+            Word::Input(input) => apply(stack, input, index).map_err(ScriptError::Eval),
+            Word::Store(name) => {
+                if self.words.contains_key(name) {
+                    return Err(ScriptError::NameInUse {
+                        name: name.clone(),
+                        span: span.clone(),
+                    });
+                }
+                let value = stack
+                    .pop()
+                    .ok_or(ScriptError::Eval(RpnError::StackUnderflow { index }))?;
+                self.variables.insert(name.clone(), value);
+                Ok(())
+            }
+            Word::Call(name) => {
+                if let Some(value) = self.variables.get(name) {
+                    stack.push(value.clone());
+                    return Ok(());
+                }
+                let body = self.body(name, span, depth)?;
+                for word in body.iter() {
+                    self.run(stack, word, span, index, depth + 1, steps)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use rpn_calculator::*;
+
+fn compiled(text: &str) -> Result<Program, RpnError> {
+    compile(&parse(text).unwrap())
+}
+
+#[test]
+fn test_compile_and_run() {
+    let program = compiled("4 8 + 7 5 - /").unwrap();
+    assert_eq!(run(&program), Ok(6));
+    assert_eq!(program.max_depth(), 3);
+    assert_eq!(program.constants(), &[4, 8, 7, 5]);
+}
+
+#[test]
+fn test_code_layout() {
+    let program = compiled("1 2 +").unwrap();
+    assert_eq!(program.code(), &[0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1]);
+}
+
+#[test]
+fn test_run_matches_evaluate() {
+    for text in [
+        "2 3 ^ 4 %",
+        "1 2 3 rot - swap over * max",
+        "7 dup dup * * neg abs",
+        "5 2 drop 3 min",
+    ] {
+        let inputs = parse(text).unwrap();
+        assert_eq!(run(&compile(&inputs).unwrap()), try_evaluate(&inputs));
+    }
+}
+
+#[test]
+fn test_compile_rejects_underflow_without_evaluating() {
+    // the division by zero before the underflow is never reached
+    assert_eq!(
+        compiled("1 0 / +"),
+        Err(RpnError::StackUnderflow { index: 3 })
+    );
+    assert_eq!(compiled("dup"), Err(RpnError::StackUnderflow { index: 0 }));
+}
+
+#[test]
+fn test_compile_rejects_final_depth() {
+    assert_eq!(compiled(""), Err(RpnError::EmptyStack));
+    assert_eq!(compiled("1 drop"), Err(RpnError::EmptyStack));
+    assert_eq!(
+        compiled("1 2 over"),
+        Err(RpnError::TooManyValues { remaining: 3 })
+    );
+}
+
+#[test]
+fn test_run_reports_arithmetic_errors_by_token() {
+    let program = compiled("1 2 + 0 /").unwrap();
+    assert_eq!(run(&program), Err(RpnError::DivisionByZero { index: 4 }));
+}
+
+#[test]
+fn test_other_backends() {
+    let program = compile(&parse_as::<BigRational>("1 3 / 1 6 / +").unwrap()).unwrap();
+    assert_eq!(run(&program).unwrap().to_string(), "1/2");
+}
@@ -0,0 +1,194 @@
+use rpn_calculator::*;
+
+fn rpn(text: &str) -> Vec<CalculatorInput> {
+    parse(text).unwrap()
+}
+
+#[test]
+fn test_precedence() {
+    assert_eq!(from_infix("3 + 4 * 2"), Ok(rpn("3 4 2 * +")));
+}
+
+#[test]
+fn test_parentheses() {
+    let inputs = from_infix("(3 + 4) * 2").unwrap();
+    assert_eq!(inputs, rpn("3 4 + 2 *"));
+    assert_eq!(evaluate(&inputs), Some(14));
+}
+
+#[test]
+fn test_left_associativity() {
+    assert_eq!(from_infix("10 - 4 - 3"), Ok(rpn("10 4 - 3 -")));
+    assert_eq!(from_infix("100/10/5"), Ok(rpn("100 10 / 5 /")));
+}
+
+#[test]
+fn test_unary_minus() {
+    assert_eq!(from_infix("-3 + 4"), Ok(rpn("-3 4 +")));
+    assert_eq!(from_infix("2 - -3"), Ok(rpn("2 -3 -")));
+    assert_eq!(from_infix("-(1 + 2)"), Ok(rpn("1 2 + neg")));
+    assert_eq!(evaluate(&from_infix("--5").unwrap()), Some(5));
+}
+
+#[test]
+fn test_most_negative_literal() {
+    assert_eq!(
+        from_infix("-2147483648"),
+        Ok(vec![CalculatorInput::Value(i32::MIN)])
+    );
+    assert_eq!(from_infix("1 + -2147483648"), Ok(rpn("1 -2147483648 +")));
+    assert_eq!(
+        from_infix("2147483648").unwrap_err().kind,
+        ParseErrorKind::InvalidToken("2147483648".to_string())
+    );
+}
+
+#[test]
+fn test_unmatched_open_parenthesis() {
+    let err = from_infix("(1 + (2 * 3)").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::MismatchedParenthesis);
+    assert_eq!(err.span, 0..1);
+}
+
+#[test]
+fn test_unmatched_close_parenthesis() {
+    let err = from_infix("1 + 2) * 3").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::MismatchedParenthesis);
+    assert_eq!(err.span, 5..6);
+}
+
+#[test]
+fn test_missing_operand() {
+    let err = from_infix("1 +").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::ExpectedOperand);
+    assert_eq!(err.span, 3..3);
+    assert_eq!(
+        from_infix("* 2").unwrap_err().kind,
+        ParseErrorKind::ExpectedOperand
+    );
+}
+
+#[test]
+fn test_missing_operator() {
+    let err = from_infix("1 2").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::ExpectedOperator);
+    assert_eq!(err.span, 2..3);
+}
+
+#[test]
+fn test_invalid_token() {
+    let err = from_infix("1 + x").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidToken("x".to_string()));
+    assert_eq!(err.span, 4..5);
+    let err = from_infix("1 , 2").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidToken(",".to_string()));
+    assert_eq!(err.span, 2..3);
+}
+
+#[test]
+fn test_to_infix_minimal_parentheses() {
+    assert_eq!(to_infix(&rpn("3 4 + 2 *")), Ok("(3 + 4) * 2".to_string()));
+    assert_eq!(to_infix(&rpn("3 4 2 * +")), Ok("3 + 4 * 2".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 - 3 -")), Ok("1 - 2 - 3".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 3 - -")), Ok("1 - (2 - 3)".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 + 3 +")), Ok("1 + 2 + 3".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 3 + +")), Ok("1 + (2 + 3)".to_string()));
+    assert_eq!(to_infix(&rpn("2 3 2 / *")), Ok("2 * (3 / 2)".to_string()));
+}
+
+#[test]
+fn test_to_infix_keeps_grouping() {
+    let inputs = rpn("2147483647 1 -1 + +");
+    let text = to_infix(&inputs).unwrap();
+    assert_eq!(text, "2147483647 + (1 + -1)");
+    assert_eq!(try_evaluate(&inputs), Ok(2147483647));
+    assert_eq!(try_evaluate(&from_infix(&text).unwrap()), Ok(2147483647));
+
+    let inputs = parse_as::<f64>("0.1 0.2 0.3 + +").unwrap();
+    let text = to_infix(&inputs).unwrap();
+    assert_eq!(
+        try_evaluate(&from_infix_as::<f64>(&text).unwrap()),
+        try_evaluate(&inputs)
+    );
+}
+
+#[test]
+fn test_to_infix_errors() {
+    assert_eq!(
+        to_infix(&rpn("1 +")),
+        Err(RpnError::StackUnderflow { index: 1 })
+    );
+    assert_eq!(
+        to_infix(&rpn("1 2")),
+        Err(RpnError::TooManyValues { remaining: 2 })
+    );
+}
+
+#[test]
+fn test_infix_round_trip() {
+    for text in ["(3 + 4) * 2", "1 - (2 - 3) / -4", "8 / (4 / 2)"] {
+        let inputs = from_infix(text).unwrap();
+        assert_eq!(to_infix(&inputs).unwrap(), text);
+    }
+}
+
+#[test]
+fn test_power_is_right_associative_and_binds_tighter_than_negation() {
+    assert_eq!(from_infix("2 ^ 3 ^ 2"), Ok(rpn("2 3 2 ^ ^")));
+    assert_eq!(from_infix("-2 ^ 2"), Ok(rpn("2 2 ^ neg")));
+    assert_eq!(from_infix("2 ^ -1"), Ok(rpn("2 -1 ^")));
+    assert_eq!(from_infix("7 % 3 * 2"), Ok(rpn("7 3 % 2 *")));
+}
+
+#[test]
+fn test_functions() {
+    assert_eq!(
+        from_infix("max(1, -abs(2 - 5)) + min(3, 4)"),
+        Ok(rpn("1 2 5 - abs neg max 3 4 min +"))
+    );
+}
+
+#[test]
+fn test_function_argument_count() {
+    let err = from_infix("min(1)").unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::ArgumentCount(2));
+    assert_eq!(err.span, 5..6);
+    assert_eq!(
+        from_infix("abs(1, 2)").unwrap_err().kind,
+        ParseErrorKind::ArgumentCount(1)
+    );
+    assert_eq!(
+        from_infix("max 1").unwrap_err().kind,
+        ParseErrorKind::InvalidToken("max".to_string())
+    );
+}
+
+#[test]
+fn test_to_infix_extended_operators() {
+    assert_eq!(to_infix(&rpn("-2 2 ^")), Ok("(-2) ^ 2".to_string()));
+    assert_eq!(to_infix(&rpn("2 2 ^ neg")), Ok("-2 ^ 2".to_string()));
+    assert_eq!(to_infix(&rpn("2 3 ^ 2 ^")), Ok("(2 ^ 3) ^ 2".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 + neg")), Ok("-(1 + 2)".to_string()));
+    assert_eq!(
+        to_infix(&rpn("1 -2 min abs")),
+        Ok("abs(min(1, -2))".to_string())
+    );
+}
+
+#[test]
+fn test_to_infix_resolves_stack_words() {
+    assert_eq!(
+        to_infix(&rpn("3 1 + dup *")),
+        Ok("(3 + 1) * (3 + 1)".to_string())
+    );
+    assert_eq!(to_infix(&rpn("1 2 swap -")), Ok("2 - 1".to_string()));
+    assert_eq!(to_infix(&rpn("1 2 3 rot drop drop")), Ok("2".to_string()));
+}
+
+#[test]
+fn test_infix_round_trip_extended() {
+    for text in ["-2 ^ 2 % 3", "2 ^ 3 ^ 2", "max(-(1 + 2), abs(-4))"] {
+        let inputs = from_infix(text).unwrap();
+        assert_eq!(to_infix(&inputs).unwrap(), text);
+    }
+}
@@ -0,0 +1,107 @@
+use rpn_calculator::*;
+
+fn eval<T: Number>(text: &str) -> Result<T, RpnError> {
+    try_evaluate(&parse_as::<T>(text).unwrap())
+}
+
+#[test]
+fn test_i64_range() {
+    assert_eq!(eval::<i64>("2147483647 1 +"), Ok(2147483648));
+    assert_eq!(
+        eval::<i64>("9223372036854775807 1 +"),
+        Err(RpnError::Overflow { index: 2 })
+    );
+}
+
+#[test]
+fn test_integer_division_truncates() {
+    assert_eq!(eval::<i64>("-7 2 /"), Ok(-3));
+    assert_eq!(eval::<BigInt>("-7 2 /"), Ok(BigInt::from(-3)));
+}
+
+#[test]
+fn test_f64_division() {
+    assert_eq!(eval::<f64>("7 2 /"), Ok(3.5));
+    assert_eq!(eval::<f64>("1.5 0.5 *"), Ok(0.75));
+    assert_eq!(
+        eval::<f64>("1 0 /"),
+        Err(RpnError::DivisionByZero { index: 2 })
+    );
+}
+
+#[test]
+fn test_f64_rejects_non_finite_results() {
+    assert_eq!(
+        eval::<f64>("10 400 ^"),
+        Err(RpnError::Overflow { index: 2 })
+    );
+    assert_eq!(
+        eval::<f64>("-8 0.5 ^"),
+        Err(RpnError::InvalidExponent { index: 2 })
+    );
+    assert!(parse_as::<f64>("inf").is_err());
+}
+
+#[test]
+fn test_big_int_does_not_overflow() {
+    assert_eq!(
+        eval::<BigInt>("2 100 ^").unwrap().to_string(),
+        "1267650600228229401496703205376"
+    );
+}
+
+#[test]
+fn test_huge_powers_overflow() {
+    assert_eq!(
+        eval::<BigInt>("2 4000000000 ^"),
+        Err(RpnError::Overflow { index: 2 })
+    );
+    assert_eq!(
+        eval::<BigRational>("1/2 -2000000000 ^"),
+        Err(RpnError::Overflow { index: 2 })
+    );
+    assert_eq!(eval::<BigInt>("-1 4000000000 ^"), Ok(BigInt::from(1)));
+    assert_eq!(eval::<BigInt>("2 1000 ^").unwrap().bits(), 1001);
+}
+
+#[test]
+fn test_rational_division_is_exact() {
+    let third = eval::<BigRational>("1 3 /").unwrap();
+    assert_eq!(third.to_string(), "1/3");
+    assert_eq!(
+        eval::<BigRational>("1 3 / 3 *"),
+        Ok(BigRational::from_integer(BigInt::from(1)))
+    );
+}
+
+#[test]
+fn test_rational_literals() {
+    assert_eq!(
+        eval::<BigRational>("0.1 0.2 +").unwrap().to_string(),
+        "3/10"
+    );
+    assert_eq!(eval::<BigRational>("-3/4 abs").unwrap().to_string(), "3/4");
+    assert!(parse_as::<BigRational>("1/0").is_err());
+}
+
+#[test]
+fn test_rational_power() {
+    assert_eq!(eval::<BigRational>("2/3 -2 ^").unwrap().to_string(), "9/4");
+    assert_eq!(
+        eval::<BigRational>("2 1/2 ^"),
+        Err(RpnError::InvalidExponent { index: 2 })
+    );
+    assert_eq!(
+        eval::<BigRational>("0 -1 ^"),
+        Err(RpnError::DivisionByZero { index: 2 })
+    );
+}
+
+#[test]
+fn test_infix_with_other_backends() {
+    let inputs = from_infix_as::<f64>("-(1.5 + 2.5) / 8").unwrap();
+    assert_eq!(evaluate(&inputs), Some(-0.5));
+    assert_eq!(to_infix(&inputs), Ok("-(1.5 + 2.5) / 8".to_string()));
+    let inputs = from_infix_as::<BigRational>("max(0.25, 1/3) * 3").unwrap();
+    assert_eq!(evaluate(&inputs).unwrap().to_string(), "1");
+}
@@ -0,0 +1,79 @@
+use rpn_calculator::*;
+
+fn eval(text: &str) -> Result<i32, RpnError> {
+    try_evaluate(&parse(text).unwrap())
+}
+
+#[test]
+fn test_dup() {
+    assert_eq!(eval("7 dup *"), Ok(49));
+}
+
+#[test]
+fn test_drop() {
+    assert_eq!(eval("1 2 drop"), Ok(1));
+}
+
+#[test]
+fn test_swap() {
+    assert_eq!(eval("2 10 swap -"), Ok(8));
+}
+
+#[test]
+fn test_over() {
+    assert_eq!(eval("3 4 over - *"), Ok(3));
+}
+
+#[test]
+fn test_rot() {
+    // 1 2 3 rot leaves 2 3 1
+    assert_eq!(eval("1 2 3 rot - -"), Ok(0));
+    assert_eq!(eval("1 2 3 rot drop -"), Ok(-1));
+}
+
+#[test]
+fn test_stack_word_underflow() {
+    assert_eq!(eval("dup"), Err(RpnError::StackUnderflow { index: 0 }));
+    assert_eq!(eval("1 swap"), Err(RpnError::StackUnderflow { index: 1 }));
+    assert_eq!(eval("1 2 rot"), Err(RpnError::StackUnderflow { index: 2 }));
+}
+
+#[test]
+fn test_modulo() {
+    assert_eq!(eval("17 5 %"), Ok(2));
+    assert_eq!(eval("-17 5 %"), Ok(-2));
+    assert_eq!(eval("1 0 %"), Err(RpnError::DivisionByZero { index: 2 }));
+}
+
+#[test]
+fn test_power() {
+    assert_eq!(eval("2 10 ^"), Ok(1024));
+    assert_eq!(eval("5 0 ^"), Ok(1));
+    assert_eq!(eval("2 31 ^"), Err(RpnError::Overflow { index: 2 }));
+    assert_eq!(eval("2 -1 ^"), Err(RpnError::InvalidExponent { index: 2 }));
+}
+
+#[test]
+fn test_negate_and_abs() {
+    assert_eq!(eval("5 neg"), Ok(-5));
+    assert_eq!(eval("-5 abs"), Ok(5));
+    assert_eq!(
+        eval("-2147483648 abs"),
+        Err(RpnError::Overflow { index: 1 })
+    );
+}
+
+#[test]
+fn test_min_and_max() {
+    assert_eq!(eval("3 -4 min"), Ok(-4));
+    assert_eq!(eval("3 -4 max"), Ok(3));
+}
+
+#[test]
+fn test_arity() {
+    assert_eq!(CalculatorInput::Value(1).arity(), 0);
+    assert_eq!(CalculatorInput::<i32>::Abs.arity(), 1);
+    assert_eq!(CalculatorInput::<i32>::Rot.arity(), 3);
+    assert_eq!(CalculatorInput::<i32>::Over.outputs(), 3);
+    assert_eq!(CalculatorInput::<i32>::Drop.outputs(), 0);
+}
@@ -0,0 +1,56 @@
+use rpn_calculator::*;
+
+#[test]
+fn test_parse_simple_expression() {
+    use CalculatorInput::*;
+    assert_eq!(
+        parse("3 4 + 2 *"),
+        Ok(vec![Value(3), Value(4), Add, Value(2), Multiply])
+    );
+}
+
+#[test]
+fn test_parse_negative_values_and_minus() {
+    use CalculatorInput::*;
+    assert_eq!(parse("-3 -"), Ok(vec![Value(-3), Subtract]));
+}
+
+#[test]
+fn test_parse_empty_text() {
+    assert_eq!(parse("   "), Ok(vec![]));
+}
+
+#[test]
+fn test_parse_error_carries_span() {
+    let err = parse("3  4x +").unwrap_err();
+    assert_eq!(err.span, 3..5);
+    assert_eq!(err.kind, ParseErrorKind::InvalidToken("4x".to_string()));
+    assert_eq!(err.to_string(), "invalid token `4x` at 3..5");
+}
+
+#[test]
+fn test_from_str_single_token() {
+    assert_eq!("/".parse(), Ok(CalculatorInput::<i32>::Divide));
+    assert_eq!("42".parse(), Ok(CalculatorInput::Value(42)));
+    assert!("&".parse::<CalculatorInput>().is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    let text = "3 4 + 2 *";
+    let inputs = parse(text).unwrap();
+    assert_eq!(to_rpn_string(&inputs), text);
+    assert_eq!(evaluate(&inputs), Some(14));
+}
+
+#[test]
+fn test_round_trip_normalizes_whitespace() {
+    let inputs = parse(" 10\t-2\n/ ").unwrap();
+    assert_eq!(to_rpn_string(&inputs), "10 -2 /");
+}
+
+#[test]
+fn test_parse_extended_operators() {
+    let text = "1 2 swap over rot dup drop % ^ neg abs 3 min 4 max";
+    assert_eq!(to_rpn_string(&parse(text).unwrap()), text);
+}
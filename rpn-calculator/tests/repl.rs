@@ -0,0 +1,80 @@
+use rpn_calculator::*;
+
+#[test]
+fn test_stack_persists_between_lines() {
+    let mut session = Session::<i32>::new();
+    assert_eq!(session.eval_line("1 2"), Ok("1 2".to_string()));
+    assert_eq!(session.eval_line("+ 10"), Ok("3 10".to_string()));
+    assert_eq!(session.eval_line("*"), Ok("30".to_string()));
+    assert_eq!(session.stack(), &[30]);
+}
+
+#[test]
+fn test_failed_line_leaves_stack_untouched() {
+    let mut session = Session::<i32>::new();
+    session.eval_line("1 2").unwrap();
+    assert_eq!(
+        session.eval_line("+ 0 /"),
+        Err(ReplError::Eval(RpnError::DivisionByZero { index: 2 }))
+    );
+    assert!(matches!(
+        session.eval_line("1 x"),
+        Err(ReplError::Script(ScriptError::UnknownWord { .. }))
+    ));
+    assert_eq!(session.stack(), &[1, 2]);
+    assert_eq!(session.history(), &["1 2".to_string()]);
+}
+
+#[test]
+fn test_clear_and_stack() {
+    let mut session = Session::<i32>::new();
+    session.eval_line("1 2 3").unwrap();
+    assert_eq!(session.eval_line(".stack"), Ok("1 2 3".to_string()));
+    assert_eq!(session.eval_line(".clear"), Ok("(empty)".to_string()));
+    assert_eq!(session.eval_line(""), Ok("(empty)".to_string()));
+}
+
+#[test]
+fn test_undo() {
+    let mut session = Session::<i32>::new();
+    session.eval_line("1 2").unwrap();
+    session.eval_line("+").unwrap();
+    session.eval_line(".clear").unwrap();
+    assert_eq!(session.eval_line(".undo"), Ok("3".to_string()));
+    assert_eq!(session.history(), &["1 2".to_string(), "+".to_string()]);
+    assert_eq!(session.eval_line(".undo"), Ok("1 2".to_string()));
+    assert_eq!(session.eval_line(".undo"), Ok("(empty)".to_string()));
+    assert_eq!(session.eval_line(".undo"), Err(ReplError::NothingToUndo));
+}
+
+#[test]
+fn test_history() {
+    let mut session = Session::<i32>::new();
+    session.eval_line("1 2").unwrap();
+    session.eval_line("swap").unwrap();
+    assert_eq!(
+        session.eval_line(".history"),
+        Ok("   1  1 2\n   2  swap".to_string())
+    );
+}
+
+#[test]
+fn test_unknown_command() {
+    let mut session = Session::<f64>::new();
+    assert_eq!(
+        session.eval_line(".quit"),
+        Err(ReplError::UnknownCommand(".quit".to_string()))
+    );
+}
+
+#[test]
+fn test_words_persist_and_undo() {
+    let mut session = Session::<i32>::new();
+    session.eval_line(": square dup * ;").unwrap();
+    session.eval_line("3 =x").unwrap();
+    assert_eq!(session.eval_line("x square"), Ok("9".to_string()));
+    session.eval_line(".undo").unwrap();
+    session.eval_line(".undo").unwrap();
+    assert_eq!(session.history(), &[": square dup * ;".to_string()]);
+    assert!(session.eval_line("x").is_err());
+}
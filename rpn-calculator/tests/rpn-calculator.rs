@@ -87,3 +87,48 @@ fn test_intermediate_error_returns_none() {
     let input = calculator_input("+ 2 2 *");
     assert_eq!(evaluate(&input), None);
 }
+
+#[test]
+fn test_try_evaluate_reports_underflow_index() {
+    let input = calculator_input("2 3 + * 4");
+    assert_eq!(
+        try_evaluate(&input),
+        Err(RpnError::StackUnderflow { index: 3 })
+    );
+}
+
+#[test]
+fn test_try_evaluate_reports_leftover_values() {
+    let input = calculator_input("1 2 3 +");
+    assert_eq!(
+        try_evaluate(&input),
+        Err(RpnError::TooManyValues { remaining: 2 })
+    );
+}
+
+#[test]
+fn test_try_evaluate_empty_input() {
+    assert_eq!(try_evaluate::<i32>(&[]), Err(RpnError::EmptyStack));
+}
+
+#[test]
+fn test_division_by_zero_returns_error() {
+    let input = calculator_input("1 0 /");
+    assert_eq!(
+        try_evaluate(&input),
+        Err(RpnError::DivisionByZero { index: 2 })
+    );
+    assert_eq!(evaluate(&input), None);
+}
+
+#[test]
+fn test_overflow_returns_error() {
+    let input = calculator_input("2147483647 1 +");
+    assert_eq!(try_evaluate(&input), Err(RpnError::Overflow { index: 2 }));
+}
+
+#[test]
+fn test_division_overflow_returns_error() {
+    let input = calculator_input("-2147483648 -1 /");
+    assert_eq!(try_evaluate(&input), Err(RpnError::Overflow { index: 2 }));
+}
@@ -0,0 +1,71 @@
+use rpn_calculator::*;
+
+#[test]
+fn test_trace_records_every_step() {
+    let trace = evaluate_traced(&parse("3 4 + 2 *").unwrap());
+    assert_eq!(trace.result, Ok(14));
+    assert_eq!(trace.steps.len(), 5);
+    assert_eq!(
+        trace.steps[2],
+        TraceStep {
+            index: 2,
+            token: CalculatorInput::Add,
+            before: vec![3, 4],
+            after: vec![7],
+        }
+    );
+    assert_eq!(trace.steps[4].before, vec![7, 2]);
+    assert_eq!(trace.steps[4].after, vec![14]);
+}
+
+#[test]
+fn test_trace_stops_at_failing_token() {
+    let trace = evaluate_traced(&parse("1 0 / 5 +").unwrap());
+    assert_eq!(trace.steps.len(), 2);
+    assert_eq!(trace.result, Err(RpnError::DivisionByZero { index: 2 }));
+}
+
+#[test]
+fn test_trace_reports_final_depth() {
+    let trace = evaluate_traced(&parse("1 2").unwrap());
+    assert_eq!(trace.steps.len(), 2);
+    assert_eq!(trace.result, Err(RpnError::TooManyValues { remaining: 2 }));
+}
+
+#[test]
+fn test_trace_matches_evaluate() {
+    for text in ["4 8 + 7 5 - /", "2 +", "1 2 3 rot swap - *", ""] {
+        let inputs = parse(text).unwrap();
+        assert_eq!(evaluate_traced(&inputs).result, try_evaluate(&inputs));
+    }
+}
+
+#[test]
+fn test_trace_table() {
+    let trace = evaluate_traced(&parse("10 2 dup * -").unwrap());
+    assert_eq!(
+        trace.to_string(),
+        "\
+# | token | before | after
+0 | 10    |        | 10
+1 | 2     | 10     | 10 2
+2 | dup   | 10 2   | 10 2 2
+3 | *     | 10 2 2 | 10 4
+4 | -     | 10 4   | 6
+result: 6"
+    );
+}
+
+#[test]
+fn test_trace_json() {
+    let trace = evaluate_traced(&parse("1 2 +").unwrap());
+    assert_eq!(
+        serde_json::to_string(&trace).unwrap(),
+        r#"{"steps":[{"index":0,"token":"1","before":[],"after":[1]},{"index":1,"token":"2","before":[1],"after":[1,2]},{"index":2,"token":"+","before":[1,2],"after":[3]}],"result":3,"error":null}"#
+    );
+    let trace = evaluate_traced(&parse_as::<f64>("1 +").unwrap());
+    assert_eq!(
+        serde_json::to_value(&trace).unwrap()["error"],
+        "stack underflow at token 1"
+    );
+}
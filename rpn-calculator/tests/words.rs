@@ -0,0 +1,173 @@
+use rpn_calculator::*;
+
+fn unknown(name: &str, span: std::ops::Range<usize>) -> ScriptError {
+    ScriptError::UnknownWord {
+        name: name.to_string(),
+        span,
+    }
+}
+
+#[test]
+fn test_define_and_use_word() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(interpreter.evaluate(": square dup * ; 7 square"), Ok(49));
+    assert_eq!(interpreter.evaluate("3 square square"), Ok(81));
+}
+
+#[test]
+fn test_words_can_use_other_words() {
+    let mut interpreter = Interpreter::<i32>::new();
+    let mut stack = vec![];
+    interpreter
+        .eval_line(&mut stack, ": cube dup square * ; : square dup * ;")
+        .unwrap();
+    interpreter.eval_line(&mut stack, "2 cube").unwrap();
+    assert_eq!(stack, vec![8]);
+}
+
+#[test]
+fn test_variables() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(interpreter.evaluate("5 =x x x *"), Ok(25));
+    assert_eq!(interpreter.variable("x"), Some(&5));
+    assert_eq!(interpreter.evaluate("x 1 + =x x"), Ok(6));
+}
+
+#[test]
+fn test_store_underflow() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(
+        interpreter.evaluate("=x"),
+        Err(ScriptError::Eval(RpnError::StackUnderflow { index: 0 }))
+    );
+}
+
+#[test]
+fn test_unknown_word() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(interpreter.evaluate("1 frob"), Err(unknown("frob", 2..6)));
+    // body lookups happen when the word runs
+    interpreter.evaluate(": twice helper helper ; 0").unwrap();
+    assert_eq!(
+        interpreter.evaluate("1 twice"),
+        Err(unknown("helper", 2..7))
+    );
+}
+
+#[test]
+fn test_redefining_user_word_replaces_it() {
+    let mut interpreter = Interpreter::<i32>::new();
+    interpreter.evaluate(": f 1 ; : g f ; : f 2 ; 0").unwrap();
+    assert_eq!(interpreter.evaluate("g"), Ok(2));
+}
+
+#[test]
+fn test_builtins_and_numbers_cannot_be_redefined() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(
+        interpreter.evaluate(": dup 1 ;"),
+        Err(ScriptError::InvalidName {
+            name: "dup".to_string(),
+            span: 2..5
+        })
+    );
+    assert!(matches!(
+        interpreter.evaluate(": 5 1 ;"),
+        Err(ScriptError::InvalidName { .. })
+    ));
+    assert!(matches!(
+        interpreter.evaluate("1 =+"),
+        Err(ScriptError::InvalidName { .. })
+    ));
+}
+
+#[test]
+fn test_words_and_variables_share_names() {
+    let mut interpreter = Interpreter::<i32>::new();
+    interpreter.evaluate("1 =x : sq dup * ; 0").unwrap();
+    assert!(matches!(
+        interpreter.evaluate(": x 2 ;"),
+        Err(ScriptError::NameInUse { .. })
+    ));
+    assert!(matches!(
+        interpreter.evaluate("2 =sq"),
+        Err(ScriptError::NameInUse { .. })
+    ));
+}
+
+#[test]
+fn test_recursion_depth_limit() {
+    let mut interpreter = Interpreter::<i32>::new().with_max_depth(8);
+    interpreter.evaluate(": forever 1 + forever ; 0").unwrap();
+    assert_eq!(
+        interpreter.evaluate("0 forever"),
+        Err(ScriptError::DepthExceeded {
+            name: "forever".to_string(),
+            span: 2..9
+        })
+    );
+}
+
+#[test]
+fn test_step_limit() {
+    let mut interpreter = Interpreter::<i32>::new().with_max_steps(1000);
+    interpreter.eval_line(&mut vec![], ": w0 0 drop ;").unwrap();
+    for level in 1..=60 {
+        let definition = format!(": w{} w{} w{} ;", level, level - 1, level - 1);
+        interpreter.eval_line(&mut vec![], &definition).unwrap();
+    }
+    assert_eq!(
+        interpreter.eval_line(&mut vec![], "1 w60"),
+        Err(ScriptError::StepsExceeded { span: 2..5 })
+    );
+    assert_eq!(
+        interpreter.expand("w60"),
+        Err(ScriptError::StepsExceeded { span: 0..0 })
+    );
+    // the budget is per line
+    interpreter.eval_line(&mut vec![], "w5").unwrap();
+    interpreter.eval_line(&mut vec![], "w5").unwrap();
+    assert_eq!(interpreter.expand("w5").unwrap().len(), 64);
+}
+
+#[test]
+fn test_malformed_definitions() {
+    let mut interpreter = Interpreter::<i32>::new();
+    assert_eq!(
+        interpreter.evaluate(": half 2 /"),
+        Err(ScriptError::UnterminatedDefinition { span: 0..1 })
+    );
+    assert!(matches!(
+        interpreter.evaluate("1 ;"),
+        Err(ScriptError::UnexpectedToken { .. })
+    ));
+    assert!(matches!(
+        interpreter.evaluate(": a : b ; ;"),
+        Err(ScriptError::UnexpectedToken { .. })
+    ));
+}
+
+#[test]
+fn test_expand() {
+    let mut interpreter = Interpreter::<i32>::new();
+    interpreter
+        .evaluate("3 =n : sq dup * ; : area n sq 2 * ; 0")
+        .unwrap();
+    assert_eq!(
+        interpreter
+            .expand("area")
+            .map(|inputs| to_rpn_string(&inputs)),
+        Ok("3 dup * 2 *".to_string())
+    );
+    interpreter.evaluate(": set =n ; 0").unwrap();
+    assert!(interpreter.expand("set").is_err());
+}
+
+#[test]
+fn test_other_backends() {
+    let mut interpreter = Interpreter::<f64>::new();
+    assert_eq!(
+        interpreter.evaluate("0.5 =rate : tax rate * ; 10 tax"),
+        Ok(5.0)
+    );
+}